@@ -0,0 +1,28 @@
+-- Add migration script here
+CREATE TABLE property_owners (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id   UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    customer_id   UUID NOT NULL REFERENCES customers(id) ON DELETE CASCADE,
+    share_percent NUMERIC(5, 2) NOT NULL DEFAULT 100
+                  CHECK (share_percent > 0 AND share_percent <= 100),
+    start_date    DATE,
+    end_date      DATE,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_date IS NULL OR start_date IS NULL OR end_date >= start_date)
+);
+
+CREATE UNIQUE INDEX property_owners_open_unique
+    ON property_owners (property_id, customer_id)
+    WHERE end_date IS NULL;
+
+CREATE INDEX property_owners_customer_id_idx ON property_owners (customer_id);
+
+INSERT INTO property_owners (property_id, customer_id, share_percent)
+SELECT id, sahip_id, 100 FROM properties WHERE sahip_id IS NOT NULL;
+
+ALTER TABLE properties DROP COLUMN sahip_id;
+
+CREATE VIEW current_property_owners AS
+SELECT * FROM property_owners
+WHERE (end_date IS NULL OR end_date > CURRENT_DATE)
+  AND (start_date IS NULL OR start_date <= CURRENT_DATE);
@@ -0,0 +1,23 @@
+-- Add migration script here
+CREATE TABLE property_transfers (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    sale_date   DATE NOT NULL,
+    sale_price  NUMERIC(14, 2),
+    currency    TEXT NOT NULL DEFAULT 'TRY',
+    danisan_id  UUID REFERENCES users(id) ON DELETE SET NULL,
+    notes       TEXT,
+    created_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX property_transfers_property_id_idx ON property_transfers (property_id);
+
+ALTER TABLE property_owners
+    ADD COLUMN transfer_in_id  UUID REFERENCES property_transfers(id) ON DELETE SET NULL,
+    ADD COLUMN transfer_out_id UUID REFERENCES property_transfers(id) ON DELETE SET NULL;
+
+CREATE OR REPLACE VIEW current_property_owners AS
+SELECT * FROM property_owners
+WHERE (end_date IS NULL OR end_date > CURRENT_DATE)
+  AND (start_date IS NULL OR start_date <= CURRENT_DATE);
@@ -0,0 +1,83 @@
+-- Add migration script here
+CREATE TABLE tenants (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ad_soyad    TEXT NOT NULL,
+    gsm         TEXT,
+    telefon     TEXT,
+    email       TEXT,
+    acil_kisi   TEXT,
+    uyruk       TEXT,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TYPE lease_status_enum AS ENUM ('draft', 'active', 'ended', 'terminated');
+
+CREATE TABLE leases (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id  UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    tenant_id    UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    start_date   DATE NOT NULL,
+    end_date     DATE,
+    monthly_rent NUMERIC(14, 2) CHECK (monthly_rent >= 0),
+    currency     TEXT NOT NULL DEFAULT 'TRY',
+    deposit      NUMERIC(14, 2) CHECK (deposit >= 0),
+    status       lease_status_enum NOT NULL DEFAULT 'active',
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_date IS NULL OR end_date >= start_date)
+);
+
+CREATE UNIQUE INDEX leases_one_active_per_property
+    ON leases (property_id)
+    WHERE status = 'active';
+
+CREATE INDEX leases_tenant_id_idx ON leases (tenant_id);
+
+-- Daire, durumu 'active' olan bir sözleşme varsa kiracılıdır; end_date'e bakılmaz. Konut
+-- kirasında süre bitince sözleşme kendiliğinden uzar (TBK 347), kiracı çıkınca sözleşme
+-- 'ended' ya da 'terminated' yapılır. Süresi geçmiş aktif sözleşmeler yaklaşan bitişler
+-- listesinde görünür.
+CREATE FUNCTION sync_kiraci_var_mi() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') THEN
+        UPDATE properties SET kiraci_var_mi = EXISTS (
+            SELECT 1 FROM leases WHERE property_id = OLD.property_id AND status = 'active'
+        ) WHERE id = OLD.property_id;
+    END IF;
+
+    IF TG_OP IN ('INSERT', 'UPDATE') THEN
+        UPDATE properties SET kiraci_var_mi = EXISTS (
+            SELECT 1 FROM leases WHERE property_id = NEW.property_id AND status = 'active'
+        ) WHERE id = NEW.property_id;
+    END IF;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER leases_sync_kiraci_var_mi
+    AFTER INSERT OR UPDATE OR DELETE ON leases
+    FOR EACH ROW EXECUTE FUNCTION sync_kiraci_var_mi();
+
+-- Önceki içe aktarmaların kiracılı işaretlediği daireler için yer tutucu kiracı ve aktif
+-- sözleşme açılır; kiracı bilgileri sonradan girilir. Böylece bayrak sözleşmelerden türetilirken
+-- mevcut işaretler kaybolmaz.
+DO $$
+DECLARE
+    property RECORD;
+    new_tenant_id UUID;
+BEGIN
+    FOR property IN SELECT id, daire_no FROM properties WHERE kiraci_var_mi LOOP
+        INSERT INTO tenants (ad_soyad)
+        VALUES ('Bilinmeyen kiracı (' || property.daire_no || ')')
+        RETURNING id INTO new_tenant_id;
+
+        INSERT INTO leases (property_id, tenant_id, start_date)
+        VALUES (property.id, new_tenant_id, CURRENT_DATE);
+    END LOOP;
+END;
+$$;
+
+-- Önceki içe aktarmaların doğrudan işaretlediği daireler sözleşmelere göre düzeltilir
+UPDATE properties p SET kiraci_var_mi = EXISTS (
+    SELECT 1 FROM leases l WHERE l.property_id = p.id AND l.status = 'active'
+);
@@ -0,0 +1,41 @@
+-- Add migration script here
+CREATE TYPE charge_kind_enum AS ENUM ('aidat', 'kira', 'diger');
+
+CREATE TABLE ledger_charges (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    customer_id UUID REFERENCES customers(id) ON DELETE SET NULL,
+    lease_id    UUID REFERENCES leases(id) ON DELETE SET NULL,
+    kind        charge_kind_enum NOT NULL,
+    period      DATE,
+    amount      NUMERIC(14, 2) NOT NULL CHECK (amount > 0),
+    currency    TEXT NOT NULL DEFAULT 'TRY' CHECK (currency ~ '^[A-Z]{3}$'),
+    due_date    DATE NOT NULL,
+    description TEXT,
+    created_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX ledger_charges_one_aidat_per_period
+    ON ledger_charges (property_id, period)
+    WHERE kind = 'aidat';
+
+CREATE INDEX ledger_charges_customer_id_idx ON ledger_charges (customer_id);
+
+CREATE TABLE ledger_payments (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    customer_id UUID REFERENCES customers(id) ON DELETE SET NULL,
+    charge_id   UUID REFERENCES ledger_charges(id) ON DELETE SET NULL,
+    amount      NUMERIC(14, 2) NOT NULL CHECK (amount > 0),
+    currency    TEXT NOT NULL DEFAULT 'TRY' CHECK (currency ~ '^[A-Z]{3}$'),
+    paid_at     DATE NOT NULL DEFAULT CURRENT_DATE,
+    method      TEXT,
+    reference   TEXT,
+    created_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX ledger_payments_property_id_idx ON ledger_payments (property_id);
+CREATE INDEX ledger_payments_customer_id_idx ON ledger_payments (customer_id);
+CREATE INDEX ledger_payments_charge_id_idx ON ledger_payments (charge_id);
@@ -0,0 +1,38 @@
+-- Add migration script here
+CREATE TYPE ticket_category_enum AS ENUM (
+    'plumbing', 'electrical', 'elevator', 'intercom', 'common_area', 'other'
+);
+
+CREATE TYPE ticket_priority_enum AS ENUM ('low', 'normal', 'high', 'urgent');
+
+CREATE TYPE ticket_status_enum AS ENUM ('open', 'assigned', 'in_progress', 'resolved');
+
+CREATE TABLE tickets (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    reported_by UUID REFERENCES customers(id) ON DELETE SET NULL,
+    title       TEXT NOT NULL,
+    description TEXT,
+    category    ticket_category_enum NOT NULL DEFAULT 'other',
+    priority    ticket_priority_enum NOT NULL DEFAULT 'normal',
+    status      ticket_status_enum NOT NULL DEFAULT 'open',
+    assigned_to UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    due_at      TIMESTAMPTZ NOT NULL,
+    assigned_at TIMESTAMPTZ,
+    started_at  TIMESTAMPTZ,
+    resolved_at TIMESTAMPTZ
+);
+
+CREATE INDEX tickets_property_id_idx ON tickets (property_id);
+CREATE INDEX tickets_reported_by_idx ON tickets (reported_by);
+CREATE INDEX tickets_status_idx ON tickets (status);
+
+CREATE TABLE ticket_comments (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id   UUID NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    comment     TEXT NOT NULL,
+    created_by  UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
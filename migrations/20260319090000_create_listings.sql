@@ -0,0 +1,26 @@
+-- Add migration script here
+CREATE TYPE listing_type_enum AS ENUM ('sale', 'rent');
+
+CREATE TYPE listing_status_enum AS ENUM ('draft', 'active', 'reserved', 'closed');
+
+CREATE TABLE listings (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id  UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    listing_type listing_type_enum NOT NULL,
+    asking_price NUMERIC(14, 2) NOT NULL CHECK (asking_price > 0),
+    currency     TEXT NOT NULL DEFAULT 'TRY' CHECK (currency ~ '^[A-Z]{3}$'),
+    status       listing_status_enum NOT NULL DEFAULT 'draft',
+    danisan_id   UUID REFERENCES users(id) ON DELETE SET NULL,
+    description  TEXT,
+    published_at TIMESTAMPTZ,
+    expires_at   TIMESTAMPTZ,
+    closed_at    TIMESTAMPTZ,
+    created_by   UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX listings_one_open_per_property_type
+    ON listings (property_id, listing_type)
+    WHERE status IN ('active', 'reserved');
+
+CREATE INDEX listings_status_idx ON listings (status);
@@ -0,0 +1,43 @@
+-- Add migration script here
+CREATE TABLE lead_stages (
+    id       UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name     TEXT NOT NULL UNIQUE,
+    position INTEGER NOT NULL,
+    is_won   BOOLEAN NOT NULL DEFAULT false,
+    is_lost  BOOLEAN NOT NULL DEFAULT false,
+    CHECK (NOT (is_won AND is_lost))
+);
+
+INSERT INTO lead_stages (name, position, is_won, is_lost) VALUES
+    ('new', 1, false, false),
+    ('contacted', 2, false, false),
+    ('viewing', 3, false, false),
+    ('offer', 4, false, false),
+    ('won', 5, true, false),
+    ('lost', 6, false, true);
+
+CREATE TABLE leads (
+    id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    customer_id      UUID NOT NULL REFERENCES customers(id) ON DELETE CASCADE,
+    stage_id         UUID NOT NULL REFERENCES lead_stages(id),
+    danisan_id       UUID REFERENCES users(id) ON DELETE SET NULL,
+    listing_type     listing_type_enum,
+    oda_sayisi       TEXT,
+    blok             TEXT,
+    cephe            cephe_enum,
+    min_net_m2       NUMERIC(8, 2),
+    min_budget       NUMERIC(14, 2),
+    max_budget       NUMERIC(14, 2),
+    currency         TEXT NOT NULL DEFAULT 'TRY' CHECK (currency ~ '^[A-Z]{3}$'),
+    notes            TEXT,
+    lost_reason      TEXT,
+    created_by       UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    stage_changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    closed_at        TIMESTAMPTZ,
+    CHECK (min_budget IS NULL OR max_budget IS NULL OR min_budget <= max_budget)
+);
+
+CREATE INDEX leads_stage_id_idx ON leads (stage_id);
+CREATE INDEX leads_customer_id_idx ON leads (customer_id);
+CREATE INDEX leads_danisan_id_idx ON leads (danisan_id);
@@ -0,0 +1,18 @@
+-- Add migration script here
+CREATE TABLE notifications (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id     UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title       TEXT NOT NULL,
+    body        TEXT,
+    lead_id     UUID REFERENCES leads(id) ON DELETE CASCADE,
+    listing_id  UUID REFERENCES listings(id) ON DELETE CASCADE,
+    read_at     TIMESTAMPTZ,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX notifications_lead_listing_unique
+    ON notifications (user_id, lead_id, listing_id);
+
+CREATE INDEX notifications_user_id_idx ON notifications (user_id, read_at);
+
+ALTER TABLE listings ADD COLUMN matched_at TIMESTAMPTZ;
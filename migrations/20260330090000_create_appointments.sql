@@ -0,0 +1,29 @@
+-- Add migration script here
+CREATE EXTENSION IF NOT EXISTS btree_gist;
+
+CREATE TYPE appointment_status_enum AS ENUM ('scheduled', 'completed', 'cancelled', 'no_show');
+
+CREATE TABLE appointments (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    customer_id UUID NOT NULL REFERENCES customers(id) ON DELETE CASCADE,
+    danisan_id  UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    lead_id     UUID REFERENCES leads(id) ON DELETE SET NULL,
+    starts_at   TIMESTAMPTZ NOT NULL,
+    ends_at     TIMESTAMPTZ NOT NULL,
+    status      appointment_status_enum NOT NULL DEFAULT 'scheduled',
+    notes       TEXT,
+    created_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_at > starts_at),
+    CONSTRAINT appointments_no_consultant_overlap EXCLUDE USING gist (
+        danisan_id WITH =,
+        tstzrange(starts_at, ends_at) WITH &&
+    ) WHERE (status = 'scheduled'),
+    CONSTRAINT appointments_no_property_overlap EXCLUDE USING gist (
+        property_id WITH =,
+        tstzrange(starts_at, ends_at) WITH &&
+    ) WHERE (status = 'scheduled')
+);
+
+CREATE INDEX appointments_customer_id_idx ON appointments (customer_id);
@@ -0,0 +1,24 @@
+-- Add migration script here
+CREATE TYPE document_category_enum AS ENUM (
+    'tapu', 'kira_sozlesmesi', 'kimlik', 'fotograf', 'diger'
+);
+
+CREATE TABLE documents (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    customer_id  UUID REFERENCES customers(id) ON DELETE CASCADE,
+    property_id  UUID REFERENCES properties(id) ON DELETE CASCADE,
+    lease_id     UUID REFERENCES leases(id) ON DELETE CASCADE,
+    category     document_category_enum NOT NULL DEFAULT 'diger',
+    file_name    TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes   BIGINT NOT NULL,
+    checksum     TEXT NOT NULL,
+    storage_key  TEXT NOT NULL UNIQUE,
+    uploaded_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (num_nonnulls(customer_id, property_id, lease_id) = 1)
+);
+
+CREATE INDEX documents_customer_id_idx ON documents (customer_id);
+CREATE INDEX documents_property_id_idx ON documents (property_id);
+CREATE INDEX documents_lease_id_idx ON documents (lease_id);
@@ -0,0 +1,18 @@
+-- Add migration script here
+CREATE TABLE property_photos (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    property_id UUID NOT NULL REFERENCES properties(id) ON DELETE CASCADE,
+    position    INT NOT NULL,
+    is_cover    BOOLEAN NOT NULL DEFAULT FALSE,
+    file_name   TEXT NOT NULL,
+    width       INT NOT NULL,
+    height      INT NOT NULL,
+    checksum    TEXT NOT NULL,
+    web_key     TEXT NOT NULL UNIQUE,
+    thumb_key   TEXT NOT NULL UNIQUE,
+    uploaded_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT property_photos_position_key UNIQUE (property_id, position) DEFERRABLE INITIALLY IMMEDIATE
+);
+
+CREATE UNIQUE INDEX property_photos_cover_idx ON property_photos (property_id) WHERE is_cover;
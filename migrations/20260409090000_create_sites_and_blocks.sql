@@ -0,0 +1,70 @@
+-- Add migration script here
+CREATE TABLE sites (
+    id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name       TEXT NOT NULL UNIQUE,
+    address    TEXT,
+    ada        TEXT,
+    parsel     TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE blocks (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    site_id      UUID NOT NULL REFERENCES sites(id) ON DELETE RESTRICT,
+    name         TEXT NOT NULL,
+    floor_count  INTEGER CHECK (floor_count IS NULL OR floor_count > 0),
+    has_elevator BOOLEAN NOT NULL DEFAULT false,
+    address      TEXT,
+    ada          TEXT,
+    parsel       TEXT,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (site_id, name)
+);
+
+INSERT INTO sites (name) VALUES ('Varsayılan Site');
+
+INSERT INTO blocks (site_id, name)
+SELECT s.id, p.blok
+FROM (SELECT DISTINCT blok FROM properties) p
+CROSS JOIN sites s;
+
+ALTER TABLE properties ADD COLUMN block_id UUID REFERENCES blocks(id) ON DELETE RESTRICT;
+
+UPDATE properties p SET block_id = b.id
+FROM blocks b
+WHERE b.name = p.blok;
+
+ALTER TABLE properties ALTER COLUMN block_id SET NOT NULL;
+
+CREATE INDEX properties_block_id_idx ON properties (block_id);
+
+-- Daire numarası yalnızca site içinde tekildir; farklı sitelerde aynı numara kullanılabilir.
+ALTER TABLE properties ADD COLUMN site_id UUID REFERENCES sites(id) ON DELETE RESTRICT;
+UPDATE properties p SET site_id = b.site_id FROM blocks b WHERE b.id = p.block_id;
+ALTER TABLE properties ALTER COLUMN site_id SET NOT NULL;
+ALTER TABLE properties DROP CONSTRAINT properties_daire_no_key;
+ALTER TABLE properties ADD CONSTRAINT properties_site_daire_no_key UNIQUE (site_id, daire_no);
+CREATE INDEX properties_site_id_idx ON properties (site_id);
+
+-- properties.blok ve properties.site_id bloktan kopyalanır; filtreler ve eşleştirme bu kolonları kullanır.
+CREATE FUNCTION sync_property_blok() RETURNS TRIGGER AS $$
+BEGIN
+    SELECT name, site_id INTO NEW.blok, NEW.site_id FROM blocks WHERE id = NEW.block_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER properties_sync_blok
+    BEFORE INSERT OR UPDATE OF block_id, blok, site_id ON properties
+    FOR EACH ROW EXECUTE FUNCTION sync_property_blok();
+
+CREATE FUNCTION sync_block_name() RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE properties SET blok = NEW.name WHERE block_id = NEW.id;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER blocks_sync_name
+    AFTER UPDATE OF name ON blocks
+    FOR EACH ROW EXECUTE FUNCTION sync_block_name();
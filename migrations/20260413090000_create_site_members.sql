@@ -0,0 +1,46 @@
+-- Add migration script here
+CREATE TYPE site_role_enum AS ENUM ('manager', 'consultant', 'viewer');
+
+CREATE TABLE site_members (
+    site_id    UUID NOT NULL REFERENCES sites(id) ON DELETE CASCADE,
+    user_id    UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role       site_role_enum NOT NULL DEFAULT 'consultant',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (site_id, user_id)
+);
+
+CREATE INDEX site_members_user_id_idx ON site_members (user_id);
+
+-- Mevcut kayıtlar ilk oluşturulan siteye taşınır.
+INSERT INTO site_members (site_id, user_id, role)
+SELECT s.id, u.id, CASE WHEN u.is_admin THEN 'manager'::site_role_enum ELSE 'consultant' END
+FROM users u
+CROSS JOIN (SELECT id FROM sites ORDER BY created_at LIMIT 1) s;
+
+-- customers
+ALTER TABLE customers ADD COLUMN site_id UUID REFERENCES sites(id) ON DELETE RESTRICT;
+UPDATE customers SET site_id = (SELECT id FROM sites ORDER BY created_at LIMIT 1);
+ALTER TABLE customers ALTER COLUMN site_id SET NOT NULL;
+ALTER TABLE customers ADD CONSTRAINT customers_id_site_key UNIQUE (id, site_id);
+CREATE INDEX customers_site_id_idx ON customers (site_id);
+
+-- reminders
+ALTER TABLE reminders ADD COLUMN site_id UUID;
+UPDATE reminders r SET site_id = c.site_id FROM customers c WHERE c.id = r.customer_id;
+ALTER TABLE reminders ALTER COLUMN site_id SET NOT NULL;
+ALTER TABLE reminders DROP CONSTRAINT reminders_customer_id_fkey;
+ALTER TABLE reminders ADD CONSTRAINT reminders_customer_site_fkey
+    FOREIGN KEY (customer_id, site_id) REFERENCES customers(id, site_id) ON DELETE CASCADE;
+CREATE INDEX reminders_site_id_idx ON reminders (site_id, reminder_date);
+
+-- tenants
+ALTER TABLE tenants ADD COLUMN site_id UUID REFERENCES sites(id) ON DELETE RESTRICT;
+UPDATE tenants SET site_id = (SELECT id FROM sites ORDER BY created_at LIMIT 1);
+ALTER TABLE tenants ALTER COLUMN site_id SET NOT NULL;
+CREATE INDEX tenants_site_id_idx ON tenants (site_id);
+
+-- notifications (site_id NULL ise tüm sitelerde gösterilir)
+ALTER TABLE notifications ADD COLUMN site_id UUID REFERENCES sites(id) ON DELETE CASCADE;
+UPDATE notifications n SET site_id = c.site_id
+FROM leads l JOIN customers c ON c.id = l.customer_id
+WHERE l.id = n.lead_id;
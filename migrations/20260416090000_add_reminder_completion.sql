@@ -0,0 +1,5 @@
+-- Hatırlatmalar tamamlandı olarak işaretlenebilir; danışman raporları bunu kullanır
+ALTER TABLE reminders ADD COLUMN completed_at TIMESTAMPTZ;
+
+CREATE INDEX reminders_created_by_idx ON reminders (created_by, reminder_date)
+    WHERE completed_at IS NULL;
@@ -0,0 +1,29 @@
+-- Her içe aktarma çalıştırması kaydedilir; aynı dosyanın tekrar yüklenmesi dosya özetinden anlaşılır
+CREATE TABLE import_runs (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    site_id       UUID NOT NULL REFERENCES sites(id) ON DELETE CASCADE,
+    kind          TEXT NOT NULL CHECK (kind IN ('properties', 'customers')),
+    file_name     TEXT NOT NULL,
+    file_hash     TEXT NOT NULL,
+    mode          TEXT NOT NULL CHECK (mode IN ('all_or_nothing', 'best_effort')),
+    status        TEXT NOT NULL CHECK (status IN ('committed', 'rolled_back')),
+    created_count INTEGER NOT NULL DEFAULT 0,
+    updated_count INTEGER NOT NULL DEFAULT 0,
+    skipped_count INTEGER NOT NULL DEFAULT 0,
+    error_count   INTEGER NOT NULL DEFAULT 0,
+    user_id       UUID REFERENCES users(id) ON DELETE SET NULL,
+    started_at    TIMESTAMPTZ NOT NULL,
+    finished_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX import_runs_file_hash_idx ON import_runs (site_id, kind, file_hash);
+
+CREATE TABLE import_run_errors (
+    id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    run_id     UUID NOT NULL REFERENCES import_runs(id) ON DELETE CASCADE,
+    row_number INTEGER NOT NULL,
+    row_key    TEXT NOT NULL,
+    message    TEXT NOT NULL
+);
+
+CREATE INDEX import_run_errors_run_idx ON import_run_errors (run_id, row_number);
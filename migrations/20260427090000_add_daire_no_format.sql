@@ -0,0 +1,55 @@
+-- daire_no kanonik "BLOK-KAPI" biçiminde tutulur (örn. A1-12); bkz. models::DaireNo.
+-- Eski içe aktarmalardan kalan "A11" gibi kayıtlar blok ve kapı numarasından yeniden oluşturulur.
+-- upper() Türkçe harfleri yerel ayara göre çevirir ya da hiç çevirmez; DaireNo'daki eşleme
+-- (i → İ, ı → I) elle uygulanır.
+CREATE FUNCTION pg_temp.canonical_daire_no(blok TEXT, kapi_no INTEGER) RETURNS TEXT AS $$
+DECLARE
+    pair TEXT[];
+BEGIN
+    blok := regexp_replace(blok, '[[:space:]./-]', '', 'g');
+    FOREACH pair SLICE 1 IN ARRAY
+        ARRAY[['i', 'İ'], ['ı', 'I'], ['ç', 'Ç'], ['ğ', 'Ğ'], ['ö', 'Ö'], ['ş', 'Ş'], ['ü', 'Ü']]
+    LOOP
+        blok := replace(blok, pair[1], pair[2]);
+    END LOOP;
+    RETURN upper(blok) || '-' || kapi_no;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+-- Yeniden oluşturulan numara site içinde başka bir daireyle çakışıyorsa ("A1-" ve "A1" blokları gibi)
+-- hangi kayıtların elle birleştirilmesi gerektiği listelenir ve taşıma durdurulur.
+DO $$
+DECLARE
+    duplicates TEXT;
+BEGIN
+    SELECT string_agg(format('%s / %s: %s', s.name, d.new_daire_no, d.old_daire_nos), '; ')
+    INTO duplicates
+    FROM (
+        SELECT site_id, new_daire_no, string_agg(daire_no, ', ' ORDER BY daire_no) AS old_daire_nos
+        FROM (
+            SELECT site_id, daire_no,
+                   CASE WHEN daire_no ~ '^[A-Z0-9ÇĞİÖŞÜ]+-(0|[1-9][0-9]*)$' THEN daire_no
+                        ELSE pg_temp.canonical_daire_no(blok, kapi_no)
+                   END AS new_daire_no
+            FROM properties
+        ) t
+        GROUP BY site_id, new_daire_no
+        HAVING COUNT(*) > 1
+    ) d
+    JOIN sites s ON s.id = d.site_id;
+
+    IF duplicates IS NOT NULL THEN
+        RAISE EXCEPTION 'daire_no dönüştürmesi aynı sitede çakışan kayıtlar üretiyor: %', duplicates
+            USING HINT = 'Çakışan daireleri birleştirip ya da blok/kapı numarasını düzeltip taşımayı yeniden çalıştırın.';
+    END IF;
+END;
+$$;
+
+UPDATE properties
+SET daire_no = pg_temp.canonical_daire_no(blok, kapi_no)
+WHERE daire_no !~ '^[A-Z0-9ÇĞİÖŞÜ]+-(0|[1-9][0-9]*)$';
+
+DROP FUNCTION pg_temp.canonical_daire_no(TEXT, INTEGER);
+
+ALTER TABLE properties ADD CONSTRAINT properties_daire_no_format
+    CHECK (daire_no ~ '^[A-Z0-9ÇĞİÖŞÜ]+-(0|[1-9][0-9]*)$');
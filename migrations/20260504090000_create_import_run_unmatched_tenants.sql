@@ -0,0 +1,11 @@
+-- Müşteri içe aktarmasında daireye bağlanamayan kiracılar
+CREATE TABLE import_run_unmatched_tenants (
+    id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    run_id     UUID NOT NULL REFERENCES import_runs(id) ON DELETE CASCADE,
+    row_number INTEGER NOT NULL,
+    daire_no   TEXT NOT NULL,
+    ad_soyad   TEXT NOT NULL,
+    reason     TEXT NOT NULL
+);
+
+CREATE INDEX import_run_unmatched_tenants_run_idx ON import_run_unmatched_tenants (run_id, row_number);
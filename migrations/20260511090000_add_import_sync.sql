@@ -0,0 +1,13 @@
+-- Eşitleme kipi: dosya mevcut verilerle karşılaştırılır, yalnızca farklar yazılır
+ALTER TABLE import_runs ADD COLUMN sync BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE import_runs ADD COLUMN missing_count INTEGER NOT NULL DEFAULT 0;
+
+-- Veritabanında olup yeni dosyada bulunmayan daireler ve sahipler
+CREATE TABLE import_run_missing_records (
+    id      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    run_id  UUID NOT NULL REFERENCES import_runs(id) ON DELETE CASCADE,
+    row_key TEXT NOT NULL,
+    message TEXT NOT NULL
+);
+
+CREATE INDEX import_run_missing_records_run_idx ON import_run_missing_records (run_id);
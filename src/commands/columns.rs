@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+
+use calamine::{Data, Range};
+use serde::Deserialize;
+
+// Bir içe aktarma alanının sayfadaki karşılığı; başlıklar normalize edilerek karşılaştırılır
+pub struct ColumnSpec {
+    pub field: &'static str,
+    pub aliases: &'static [&'static str],
+    pub required: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ColumnError {
+    #[error("Zorunlu sütunlar eksik: {0}")]
+    Missing(String),
+    #[error("'{field}' alanı için birden fazla sütun eşleşti: {headers}")]
+    Ambiguous { field: String, headers: String },
+    #[error("Eşleme dosyasında bilinmeyen alan: {0}")]
+    UnknownField(String),
+    #[error("Eşleme dosyası okunamadı: {0}")]
+    Config(String),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HeaderNames {
+    One(String),
+    Many(Vec<String>),
+}
+
+// Kullanıcının verdiği alan → başlık eşlemesi, örn. TOML'da: sahip_ad_soyad = "Ev Sahibi"
+#[derive(Default)]
+pub struct ColumnConfig {
+    headers: HashMap<String, Vec<String>>,
+}
+
+impl ColumnConfig {
+    pub fn load(path: &str) -> Result<Self, ColumnError> {
+        let text = std::fs::read_to_string(path).map_err(|e| ColumnError::Config(e.to_string()))?;
+        let raw: HashMap<String, HeaderNames> = if path.ends_with(".json") {
+            serde_json::from_str(&text).map_err(|e| ColumnError::Config(e.to_string()))?
+        } else {
+            toml::from_str(&text).map_err(|e| ColumnError::Config(e.to_string()))?
+        };
+
+        let headers = raw
+            .into_iter()
+            .map(|(field, names)| {
+                let names = match names {
+                    HeaderNames::One(name) => vec![name],
+                    HeaderNames::Many(names) => names,
+                };
+                (field, names)
+            })
+            .collect();
+
+        Ok(Self { headers })
+    }
+}
+
+pub struct ColumnMap {
+    indices: HashMap<&'static str, usize>,
+}
+
+impl ColumnMap {
+    // Başlık satırındaki her alanın sütun indeksini bulur. Eşleme dosyasında
+    // verilen başlıklar o alanın varsayılan adlarının yerine geçer.
+    pub fn resolve(
+        header: &[Data],
+        specs: &[ColumnSpec],
+        config: &ColumnConfig,
+    ) -> Result<Self, ColumnError> {
+        if let Some(field) = config
+            .headers
+            .keys()
+            .find(|f| !specs.iter().any(|s| s.field == f.as_str()))
+        {
+            return Err(ColumnError::UnknownField(field.clone()));
+        }
+
+        let normalized: Vec<String> = header
+            .iter()
+            .map(|cell| match cell {
+                Data::String(s) => normalize_header(s),
+                Data::Empty => String::new(),
+                other => normalize_header(&other.to_string()),
+            })
+            .collect();
+
+        let mut indices = HashMap::new();
+        let mut missing = Vec::new();
+
+        for spec in specs {
+            let names: Vec<String> = match config.headers.get(spec.field) {
+                Some(names) => names.iter().map(|n| normalize_header(n)).collect(),
+                None => spec.aliases.iter().map(|a| normalize_header(a)).collect(),
+            };
+
+            let matches: Vec<usize> = normalized
+                .iter()
+                .enumerate()
+                .filter(|(_, h)| !h.is_empty() && names.contains(h))
+                .map(|(i, _)| i)
+                .collect();
+
+            match matches.as_slice() {
+                [] if spec.required => missing.push(spec),
+                [] => {}
+                [i] => {
+                    indices.insert(spec.field, *i);
+                }
+                _ => {
+                    return Err(ColumnError::Ambiguous {
+                        field: spec.field.to_string(),
+                        headers: matches
+                            .iter()
+                            .map(|i| header[*i].to_string())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    });
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            let list = missing
+                .iter()
+                .map(|spec| match config.headers.get(spec.field) {
+                    Some(names) => format!("{} ({})", spec.field, names.join(" / ")),
+                    None => format!("{} ({})", spec.field, spec.aliases.join(" / ")),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(ColumnError::Missing(list));
+        }
+
+        Ok(Self { indices })
+    }
+
+    pub fn index(&self, field: &str) -> Option<usize> {
+        self.indices.get(field).copied()
+    }
+
+    pub fn cell<'a>(&self, row: &'a [Data], field: &str) -> Option<&'a Data> {
+        self.index(field).and_then(|i| row.get(i))
+    }
+}
+
+// Sayfanın ilk satırını başlık kabul eder; eşleme dosyası verildiyse onu da uygular
+pub fn resolve_sheet(
+    sheet: &Range<Data>,
+    specs: &[ColumnSpec],
+    config_path: Option<&str>,
+) -> Result<ColumnMap, ColumnError> {
+    let config = match config_path {
+        Some(path) => ColumnConfig::load(path)?,
+        None => ColumnConfig::default(),
+    };
+    let header = sheet.rows().next().unwrap_or(&[]);
+    ColumnMap::resolve(header, specs, &config)
+}
+
+// "Mülk Sahibi GSM", "MulkSahibiGsm" ve "mülk_sahibi_gsm" aynı başlık sayılır
+pub fn normalize_header(header: &str) -> String {
+    header
+        .chars()
+        .filter_map(|c| match c {
+            'ç' | 'Ç' => Some('c'),
+            'ğ' | 'Ğ' => Some('g'),
+            'ı' | 'I' | 'İ' | 'i' => Some('i'),
+            'ö' | 'Ö' => Some('o'),
+            'ş' | 'Ş' => Some('s'),
+            'ü' | 'Ü' => Some('u'),
+            '²' => Some('2'),
+            c if c.is_alphanumeric() => c.to_lowercase().next(),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECS: &[ColumnSpec] = &[
+        ColumnSpec {
+            field: "daire_no",
+            aliases: &["Daire No", "Daire"],
+            required: true,
+        },
+        ColumnSpec {
+            field: "sahip_gsm",
+            aliases: &["Mülk Sahibi GSM", "Sahip Telefon"],
+            required: true,
+        },
+        ColumnSpec {
+            field: "brut_m2",
+            aliases: &["Brüt m²"],
+            required: false,
+        },
+    ];
+
+    fn header(names: &[&str]) -> Vec<Data> {
+        names
+            .iter()
+            .map(|n| match *n {
+                "" => Data::Empty,
+                n => Data::String(n.to_string()),
+            })
+            .collect()
+    }
+
+    fn config(field: &str, names: &[&str]) -> ColumnConfig {
+        ColumnConfig {
+            headers: HashMap::from([(
+                field.to_string(),
+                names.iter().map(|n| n.to_string()).collect(),
+            )]),
+        }
+    }
+
+    #[test]
+    fn normalizes_turkish_headers() {
+        let cases = [
+            ("Mülk Sahibi GSM", "mulksahibigsm"),
+            ("MulkSahibiGsm", "mulksahibigsm"),
+            ("mülk_sahibi_gsm", "mulksahibigsm"),
+            // Noktalı ve noktasız i aynı harfe iner
+            ("İLÇE", "ilce"),
+            ("ILCE", "ilce"),
+            ("ılçe", "ilce"),
+            ("ŞEHİR", "sehir"),
+            ("Görüşme Ağı", "gorusmeagi"),
+            ("Brüt m²", "brutm2"),
+            ("  Daire No.  ", "daireno"),
+            ("", ""),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(normalize_header(input), expected, "girdi: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn resolves_columns_by_alias() {
+        let row = header(&["", "MÜLK SAHİBİ GSM", "daire_no", "Not"]);
+        let map = ColumnMap::resolve(&row, SPECS, &ColumnConfig::default()).unwrap();
+
+        assert_eq!(map.index("daire_no"), Some(2));
+        assert_eq!(map.index("sahip_gsm"), Some(1));
+        assert_eq!(map.index("brut_m2"), None);
+    }
+
+    #[test]
+    fn resolves_non_string_header_cells() {
+        let row = vec![
+            Data::String("Daire".into()),
+            Data::String("Sahip Telefon".into()),
+            Data::Float(2.0),
+        ];
+        let specs = [ColumnSpec {
+            field: "yil",
+            aliases: &["2"],
+            required: true,
+        }];
+        let map = ColumnMap::resolve(&row, &specs, &ColumnConfig::default()).unwrap();
+        assert_eq!(map.index("yil"), Some(2));
+    }
+
+    #[test]
+    fn rejects_ambiguous_columns() {
+        let row = header(&["Daire No", "Mülk Sahibi GSM", "Sahip Telefon"]);
+        let err = ColumnMap::resolve(&row, SPECS, &ColumnConfig::default())
+            .err()
+            .unwrap();
+
+        match err {
+            ColumnError::Ambiguous { field, headers } => {
+                assert_eq!(field, "sahip_gsm");
+                assert_eq!(headers, "Mülk Sahibi GSM, Sahip Telefon");
+            }
+            other => panic!("beklenmeyen hata: {}", other),
+        }
+    }
+
+    #[test]
+    fn reports_all_missing_required_columns() {
+        let row = header(&["Brüt m²", "Not"]);
+        let err = ColumnMap::resolve(&row, SPECS, &ColumnConfig::default())
+            .err()
+            .unwrap();
+
+        match err {
+            ColumnError::Missing(list) => assert_eq!(
+                list,
+                "daire_no (Daire No / Daire), sahip_gsm (Mülk Sahibi GSM / Sahip Telefon)"
+            ),
+            other => panic!("beklenmeyen hata: {}", other),
+        }
+    }
+
+    #[test]
+    fn config_headers_replace_aliases() {
+        let row = header(&["Daire No", "Ev Sahibi Cep", "Mülk Sahibi GSM"]);
+        let map =
+            ColumnMap::resolve(&row, SPECS, &config("sahip_gsm", &["ev sahibi cep"])).unwrap();
+        assert_eq!(map.index("sahip_gsm"), Some(1));
+
+        // Eşlemede verilen başlık yoksa varsayılan adlara dönülmez
+        let row = header(&["Daire No", "Mülk Sahibi GSM"]);
+        let err = ColumnMap::resolve(&row, SPECS, &config("sahip_gsm", &["Ev Sahibi Cep"]))
+            .err()
+            .unwrap();
+        match err {
+            ColumnError::Missing(list) => assert_eq!(list, "sahip_gsm (Ev Sahibi Cep)"),
+            other => panic!("beklenmeyen hata: {}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_config_fields() {
+        let row = header(&["Daire No", "Mülk Sahibi GSM"]);
+        let err = ColumnMap::resolve(&row, SPECS, &config("kat", &["Kat"]))
+            .err()
+            .unwrap();
+        assert!(matches!(err, ColumnError::UnknownField(f) if f == "kat"));
+    }
+}
@@ -0,0 +1,100 @@
+use axum::extract::Query;
+use axum::http::Uri;
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::models::{ExportFormat, PropertyFilter};
+
+// Dışa aktarma dosya adı, site adı ve --filter "blok=A&kiraci_var_mi=true" biçimindeki
+// filtreyi ayırır; filtre API'deki sorgu parametreleriyle aynıdır
+pub fn parse_export_args(args: &[String]) -> (Vec<String>, Option<String>) {
+    let mut positional = Vec::new();
+    let mut filter = None;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--filter" => filter = iter.next().cloned(),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    (positional, filter)
+}
+
+fn output_format(path: &str) -> Result<ExportFormat, String> {
+    ExportFormat::from_path(path).ok_or_else(|| {
+        format!(
+            "Desteklenmeyen dosya türü: {} (xlsx ya da csv olmalı)",
+            path
+        )
+    })
+}
+
+async fn find_site(db: &Database, name: &str) -> Result<Uuid, String> {
+    db.list_sites()
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|s| s.name == name)
+        .map(|s| s.id)
+        .ok_or_else(|| format!("Site bulunamadı: {}", name))
+}
+
+fn parse_filter(filter: Option<&str>) -> Result<PropertyFilter, String> {
+    let Some(filter) = filter else {
+        return Ok(PropertyFilter::default());
+    };
+    let uri: Uri = format!("/?{}", filter)
+        .parse()
+        .map_err(|_| format!("Geçersiz filtre: {}", filter))?;
+    Query::<PropertyFilter>::try_from_uri(&uri)
+        .map(|Query(filter)| filter)
+        .map_err(|e| format!("Geçersiz filtre: {}", e.body_text()))
+}
+
+fn save(path: &str, written: std::io::Result<()>, count: usize) {
+    match written {
+        Ok(()) => println!("{} kayıt yazıldı: {}", count, path),
+        Err(e) => eprintln!("Dosya yazılamadı: {}", e),
+    }
+}
+
+pub async fn run_customers(db: &Database, path: &str, site: &str) {
+    let result = async {
+        let format = output_format(path)?;
+        let site_id = find_site(db, site).await?;
+        let rows = db
+            .export_customers(site_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let data = crate::exports::customers(&rows, format).map_err(|e| e.to_string())?;
+        Ok::<_, String>((rows.len(), data))
+    }
+    .await;
+
+    match result {
+        Ok((count, data)) => save(path, std::fs::write(path, data), count),
+        Err(e) => eprintln!("{}", e),
+    }
+}
+
+pub async fn run_properties(db: &Database, path: &str, site: &str, filter: Option<&str>) {
+    let result = async {
+        let format = output_format(path)?;
+        let filter = parse_filter(filter)?;
+        let site_id = find_site(db, site).await?;
+        let rows = db
+            .export_properties(site_id, &filter)
+            .await
+            .map_err(|e| e.to_string())?;
+        let data = crate::exports::properties(&rows, format).map_err(|e| e.to_string())?;
+        Ok::<_, String>((rows.len(), data))
+    }
+    .await;
+
+    match result {
+        Ok((count, data)) => save(path, std::fs::write(path, data), count),
+        Err(e) => eprintln!("{}", e),
+    }
+}
@@ -1,16 +1,515 @@
-use crate::database::Database;
-use calamine::{Data, Reader, Xlsx, open_workbook};
+use crate::commands::columns::{ColumnSpec, resolve_sheet};
+use crate::commands::import_report::{ImportReport, NO_CHANGES, RowStatus};
+use crate::commands::import_run::{
+    ImportContext, ImportError, ImportFile, ImportRunner, print_result,
+};
+use crate::commands::{ImportKind, ImportOptions};
+use crate::database::{Database, ImportSession};
+use crate::models::{
+    CreateCustomer, CreateTenant, Customer, DaireNo, MissingRecord, NewPropertyOwner, Property,
+    Tenant, UnmatchedTenant,
+};
+use bigdecimal::BigDecimal;
+use calamine::Data;
+use std::collections::{BTreeMap, HashSet};
+use uuid::Uuid;
 
-pub async fn run(db: &Database, filepath: &str) {
-    let mut workbook: Xlsx<_> = open_workbook(filepath).expect("Dosya açılamadı");
-    let sheet = workbook.worksheet_range_at(0).unwrap().unwrap();
+// Dosyadaki kişiler sahip olarak atanır, hisseler eşit bölünür; kuruş farkı ilk sahibe yazılır.
+// Listede olmayan güncel sahiplerin sahipliği sonlandırılır.
+async fn assign_owners(
+    session: &mut ImportSession,
+    property_id: Uuid,
+    customer_ids: &[Uuid],
+) -> Result<(), sqlx::Error> {
+    let share = match customer_ids.len() {
+        0 => BigDecimal::from(0),
+        n => (BigDecimal::from(100) / BigDecimal::from(n as i64)).with_scale(2),
+    };
+    let rest = BigDecimal::from(100) - &share * BigDecimal::from(customer_ids.len() as i64);
+    let owners: Vec<NewPropertyOwner> = customer_ids
+        .iter()
+        .enumerate()
+        .map(|(i, &customer_id)| NewPropertyOwner {
+            customer_id,
+            share_percent: if i == 0 {
+                &share + &rest
+            } else {
+                share.clone()
+            },
+            start_date: None,
+        })
+        .collect();
+    session.set_property_owners(property_id, &owners).await
+}
+
+const COLUMNS: &[ColumnSpec] = &[
+    ColumnSpec {
+        field: "daire_no",
+        aliases: &["Daire", "Daire No", "DaireNo", "Bağımsız Bölüm"],
+        required: true,
+    },
+    ColumnSpec {
+        field: "sahip_ad_soyad",
+        aliases: &["MulkSahibi", "Mülk Sahibi", "Ev Sahibi", "Malik"],
+        required: true,
+    },
+    ColumnSpec {
+        field: "sahip_gsm",
+        aliases: &["MulkSahibiGsm", "Mülk Sahibi GSM", "Ev Sahibi GSM"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "sahip_telefon",
+        aliases: &["MulkSahibiTlf", "MulkSahibiTelefon", "Mülk Sahibi Telefon"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "sahip_email",
+        aliases: &[
+            "MulkSahibiEmail",
+            "Mülk Sahibi E-posta",
+            "Mülk Sahibi Email",
+        ],
+        required: false,
+    },
+    ColumnSpec {
+        field: "sahip_acil_kisi",
+        aliases: &[
+            "MulkSahibiAcilDurumdaUlasilacakKisi",
+            "Mülk Sahibi Acil Durumda Ulaşılacak Kişi",
+            "Mülk Sahibi Acil Kişi",
+        ],
+        required: false,
+    },
+    ColumnSpec {
+        field: "sahip_uyruk",
+        aliases: &["MulkSahibiUyruk", "Mülk Sahibi Uyruk"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "kiraci_ad_soyad",
+        aliases: &["KiraciAdi", "Kiracı Adı", "Kiracı"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "kiraci_gsm",
+        aliases: &["KiraciGsm", "Kiracı GSM"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "kiraci_telefon",
+        aliases: &["KiraciTelefon", "KiraciTlf", "Kiracı Telefon"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "kiraci_email",
+        aliases: &["KiraciEmail", "Kiracı E-posta", "Kiracı Email"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "kiraci_acil_kisi",
+        aliases: &[
+            "KiraciAcilDurumdaUlasilacakKisi",
+            "Kiracı Acil Durumda Ulaşılacak Kişi",
+            "Kiracı Acil Kişi",
+        ],
+        required: false,
+    },
+    ColumnSpec {
+        field: "kiraci_uyruk",
+        aliases: &["KiraciUyruk", "Kiracı Uyruk"],
+        required: false,
+    },
+];
+
+fn non_empty(value: String) -> Option<String> {
+    if value.is_empty() { None } else { Some(value) }
+}
+
+// Aktif sözleşmedeki kiracı adı büyük/küçük harf ve boşluk farkı gözetmeden karşılaştırılır
+fn same_name(a: &str, b: &str) -> bool {
+    let normalize = |s: &str| {
+        s.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    };
+    normalize(a) == normalize(b)
+}
+
+// Dosyada dolu olup kayıttakinden farklı olan iletişim bilgileri
+fn changed_fields<const N: usize>(
+    fields: [(&'static str, &Option<String>, &Option<String>); N],
+) -> Vec<&'static str> {
+    fields
+        .into_iter()
+        .filter(|(_, new, old)| new.is_some() && new != old)
+        .map(|(label, _, _)| label)
+        .collect()
+}
 
-    let mut created = 0;
-    let mut skipped = 0;
+fn tenant_changes(tenant: &Tenant, body: &CreateTenant) -> Vec<&'static str> {
+    changed_fields([
+        ("GSM", &body.gsm, &tenant.gsm),
+        ("Telefon", &body.telefon, &tenant.telefon),
+        ("E-posta", &body.email, &tenant.email),
+        ("Acil kişi", &body.acil_kisi, &tenant.acil_kisi),
+        ("Uyruk", &body.uyruk, &tenant.uyruk),
+    ])
+}
+
+// GSM müşteri eşleştirmesinde kullanıldığı için karşılaştırılmaz
+fn customer_changes(customer: &Customer, body: &CreateCustomer) -> Vec<&'static str> {
+    changed_fields([
+        ("Telefon", &body.telefon, &customer.telefon),
+        ("E-posta", &body.email, &customer.email),
+        ("Acil kişi", &body.acil_kisi, &customer.acil_kisi),
+        ("Uyruk", &body.uyruk, &customer.uyruk),
+    ])
+}
+
+// Bir satır için yapılacak değişiklikler ve rapordaki açıklaması
+struct RowPlan {
+    owner: Option<OwnerPlan>,
+    tenant: Option<TenantPlan>,
+    // Kiracı daireye bağlanamadıysa nedeni
+    unmatched_tenant: Option<String>,
+}
+
+struct OwnerPlan {
+    body: CreateCustomer,
+    existing: Option<Uuid>,
+    // Yalnızca eşitlemede güncellenir; sahiplik daire bazında ayrıca atanır
+    changes: Vec<&'static str>,
+}
+
+struct TenantPlan {
+    body: CreateTenant,
+    existing: Option<Uuid>,
+    changes: Vec<&'static str>,
+    // Dairede aktif sözleşme yoksa kiracı için yeni sözleşme açılır
+    link: bool,
+}
 
-    for row in sheet.rows().skip(1) {
-        let get = |i: usize| -> String {
-            row.get(i)
+impl RowPlan {
+    fn status(&self) -> RowStatus {
+        let new_owner = matches!(&self.owner, Some(OwnerPlan { existing: None, .. }));
+        let new_tenant = matches!(&self.tenant, Some(TenantPlan { existing: None, .. }));
+        let owner_changed = self.owner.as_ref().is_some_and(|o| !o.changes.is_empty());
+        let tenant_changed = self
+            .tenant
+            .as_ref()
+            .is_some_and(|t| t.link || !t.changes.is_empty());
+
+        if new_owner || new_tenant {
+            RowStatus::Create
+        } else if owner_changed || tenant_changed {
+            RowStatus::Update
+        } else {
+            RowStatus::Skip
+        }
+    }
+
+    fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(owner) = &self.owner {
+            if owner.existing.is_none() {
+                parts.push(format!("Yeni müşteri: {}", owner.body.ad_soyad));
+            }
+            if !owner.changes.is_empty() {
+                parts.push(format!(
+                    "Sahip bilgileri güncellenir: {}",
+                    owner.changes.join(", ")
+                ));
+            }
+        }
+        if let Some(tenant) = &self.tenant {
+            if tenant.existing.is_none() {
+                parts.push(format!("Yeni kiracı: {}", tenant.body.ad_soyad));
+            }
+            if !tenant.changes.is_empty() {
+                parts.push(format!(
+                    "Kiracı bilgileri güncellenir: {}",
+                    tenant.changes.join(", ")
+                ));
+            }
+            if tenant.link {
+                parts.push(format!(
+                    "{} için aktif kira sözleşmesi açılır",
+                    tenant.body.ad_soyad
+                ));
+            }
+        }
+        if let Some(reason) = &self.unmatched_tenant {
+            parts.push(format!("Kiracı eşleşmedi: {}", reason));
+        }
+        if parts.is_empty() {
+            NO_CHANGES.to_string()
+        } else {
+            parts.join("; ")
+        }
+    }
+}
+
+async fn plan_owner(
+    session: &mut ImportSession,
+    site_id: Uuid,
+    get: &impl Fn(&str) -> String,
+    sync: bool,
+) -> Result<Option<OwnerPlan>, sqlx::Error> {
+    let sahip_adi = get("sahip_ad_soyad");
+    if sahip_adi.is_empty() {
+        return Ok(None);
+    }
+
+    let body = CreateCustomer {
+        ad_soyad: sahip_adi,
+        gsm: non_empty(get("sahip_gsm")),
+        telefon: non_empty(get("sahip_telefon")),
+        email: non_empty(get("sahip_email")),
+        acil_kisi: non_empty(get("sahip_acil_kisi")),
+        uyruk: non_empty(get("sahip_uyruk")),
+        en_son_gorusuldu: None,
+        danisan_id: None,
+    };
+    let existing = session.find_customer(site_id, &body).await?;
+    let changes = match &existing {
+        Some(customer) if sync => customer_changes(customer, &body),
+        _ => Vec::new(),
+    };
+
+    Ok(Some(OwnerPlan {
+        body,
+        existing: existing.map(|c| c.id),
+        changes,
+    }))
+}
+
+// Err: kiracı daireye bağlanamadı, nedeni rapora yazılır
+async fn plan_tenant(
+    session: &mut ImportSession,
+    site_id: Uuid,
+    property: &Property,
+    body: CreateTenant,
+) -> Result<Result<TenantPlan, String>, sqlx::Error> {
+    match session.active_lease_tenant(property.id).await? {
+        Some(current) if same_name(&current.ad_soyad, &body.ad_soyad) => Ok(Ok(TenantPlan {
+            changes: tenant_changes(&current, &body),
+            existing: Some(current.id),
+            body,
+            link: false,
+        })),
+        // Mevcut sözleşme dosyaya bakılarak sonlandırılmaz
+        Some(current) => Ok(Err(format!(
+            "Aktif kira sözleşmesi başka kiracıya ait: {}",
+            current.ad_soyad
+        ))),
+        None => {
+            let existing = session.find_tenant(site_id, &body).await?;
+            Ok(Ok(TenantPlan {
+                changes: existing
+                    .as_ref()
+                    .map(|t| tenant_changes(t, &body))
+                    .unwrap_or_default(),
+                existing: existing.map(|t| t.id),
+                body,
+                link: true,
+            }))
+        }
+    }
+}
+
+async fn plan_row(
+    session: &mut ImportSession,
+    site_id: Uuid,
+    property: &Property,
+    get: impl Fn(&str) -> String,
+    sync: bool,
+) -> Result<RowPlan, sqlx::Error> {
+    let owner = plan_owner(session, site_id, &get, sync).await?;
+
+    let kiraci_adi = get("kiraci_ad_soyad");
+    let (tenant, unmatched_tenant) = if kiraci_adi.is_empty() {
+        (None, None)
+    } else {
+        let body = CreateTenant {
+            ad_soyad: kiraci_adi,
+            gsm: non_empty(get("kiraci_gsm")),
+            telefon: non_empty(get("kiraci_telefon")),
+            email: non_empty(get("kiraci_email")),
+            acil_kisi: non_empty(get("kiraci_acil_kisi")),
+            uyruk: non_empty(get("kiraci_uyruk")),
+        };
+        match plan_tenant(session, site_id, property, body).await? {
+            Ok(plan) => (Some(plan), None),
+            Err(reason) => (None, Some(reason)),
+        }
+    };
+
+    Ok(RowPlan {
+        owner,
+        tenant,
+        unmatched_tenant,
+    })
+}
+
+async fn apply_row(
+    session: &mut ImportSession,
+    site_id: Uuid,
+    property: &Property,
+    plan: &RowPlan,
+) -> Result<Option<Uuid>, sqlx::Error> {
+    let mut owner_id = None;
+    if let Some(owner) = &plan.owner {
+        let customer_id = match owner.existing {
+            Some(id) => id,
+            None => session.create_customer(site_id, &owner.body).await?.id,
+        };
+        if !owner.changes.is_empty() {
+            session
+                .update_customer_contacts(customer_id, &owner.body)
+                .await?;
+        }
+        owner_id = Some(customer_id);
+    }
+
+    if let Some(tenant) = &plan.tenant {
+        let tenant_id = match tenant.existing {
+            Some(id) => id,
+            None => session.create_tenant(site_id, &tenant.body).await?.id,
+        };
+        if !tenant.changes.is_empty() {
+            session
+                .update_tenant_contacts(tenant_id, &tenant.body)
+                .await?;
+        }
+        if tenant.link {
+            session.create_active_lease(property.id, tenant_id).await?;
+        }
+    }
+
+    Ok(owner_id)
+}
+
+// Dosyanın bir daire için istediği sahipler; ortak sahipler ayrı satırlarda yazılabildiği için
+// sahiplik tüm satırlar okunduktan sonra daire başına bir kez atanır, sonuç ilk satıra yazılır
+struct UnitOwners {
+    property_id: Uuid,
+    row: usize,
+    owners: Vec<(Uuid, String)>,
+}
+
+#[derive(Debug, PartialEq)]
+struct OwnerDiff {
+    // Fark yazılırsa dairenin sahip listesi: dosyadakiler ve korunanlar
+    owners: Vec<Uuid>,
+    added: Vec<String>,
+    removed: Vec<String>,
+    // Eşitlemede dosyada olmayan, --unlink-missing verilmediği için korunan sahipler
+    kept: Vec<String>,
+}
+
+impl OwnerDiff {
+    fn changed(&self) -> bool {
+        !self.added.is_empty() || !self.removed.is_empty()
+    }
+}
+
+// Güncel sahipleri dosyadakilerle karşılaştırır; unlink verilmezse dosyada olmayanlar korunur
+fn owner_diff(current: &[(Uuid, String)], file: &[(Uuid, String)], unlink: bool) -> OwnerDiff {
+    let added: Vec<String> = file
+        .iter()
+        .filter(|(id, _)| !current.iter().any(|(c, _)| c == id))
+        .map(|(_, name)| name.clone())
+        .collect();
+    let missing: Vec<&(Uuid, String)> = current
+        .iter()
+        .filter(|(id, _)| !file.iter().any(|(o, _)| o == id))
+        .collect();
+    let (removed, kept) = if unlink {
+        (missing, Vec::new())
+    } else {
+        (Vec::new(), missing)
+    };
+
+    let names = |owners: &[&(Uuid, String)]| owners.iter().map(|(_, name)| name.clone()).collect();
+    OwnerDiff {
+        owners: file
+            .iter()
+            .chain(kept.iter().copied())
+            .map(|(id, _)| *id)
+            .collect(),
+        added,
+        removed: names(&removed),
+        kept: names(&kept),
+    }
+}
+
+// Sahiplik yalnızca fark varsa yeniden yazılır
+async fn sync_owners(
+    session: &mut ImportSession,
+    unit: &UnitOwners,
+    unlink: bool,
+) -> Result<OwnerDiff, sqlx::Error> {
+    let current = session.current_owners(unit.property_id).await?;
+    let diff = owner_diff(&current, &unit.owners, unlink);
+    if diff.changed() {
+        assign_owners(session, unit.property_id, &diff.owners).await?;
+    }
+    Ok(diff)
+}
+
+pub async fn run(db: &Database, filepath: &str, site: &str, options: &ImportOptions) {
+    let file = match ImportFile::read(filepath) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+    let ctx = match ImportContext::for_cli(db, site, options).await {
+        Ok(ctx) => ctx,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    let result = import(db, &file, ctx, options).await;
+    print_result(result, &file, options);
+}
+
+pub async fn import(
+    db: &Database,
+    file: &ImportFile,
+    ctx: ImportContext<'_>,
+    options: &ImportOptions,
+) -> Result<ImportReport, ImportError> {
+    let sheet = file.sheet(&options.source)?;
+    let cols = resolve_sheet(&sheet, COLUMNS, options.columns_path.as_deref())?;
+    let total_rows = sheet.height().saturating_sub(1);
+
+    let mut report = ImportReport::new(options.dry_run);
+    let mut runner = ImportRunner::begin(
+        db,
+        ImportKind::Customers,
+        file,
+        ctx,
+        options,
+        &mut report,
+        total_rows,
+    )
+    .await?;
+    let site_id = runner.site_id;
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut units: BTreeMap<String, UnitOwners> = BTreeMap::new();
+    let mut failed: HashSet<String> = HashSet::new();
+
+    for (i, row) in sheet.rows().enumerate().skip(1) {
+        runner.progress(i - 1, &report);
+
+        let get = |field: &str| -> String {
+            cols.cell(row, field)
                 .map(|v| match v {
                     Data::String(s) => s.trim().to_string(),
                     Data::Float(f) => f.to_string(),
@@ -20,71 +519,244 @@ pub async fn run(db: &Database, filepath: &str) {
                 .unwrap_or_default()
         };
 
-        let daire_no = get(0);
-        if daire_no.is_empty() {
+        let raw_daire_no = get("daire_no");
+        if raw_daire_no.is_empty() {
             continue;
         }
-
-        let daire_no = {
-            let digits: String = daire_no.chars().filter(|c| c.is_ascii_digit()).collect();
-            let letters: String = daire_no.chars().filter(|c| !c.is_ascii_digit()).collect();
-            if digits.len() >= 3 {
-                let blok_no = &digits[..digits.len() - 3].trim_start_matches('0');
-                let kapi_no = digits[digits.len() - 3..].trim_start_matches('0');
-                let blok_no = if blok_no.is_empty() { "0" } else { blok_no };
-                let kapi_no = if kapi_no.is_empty() { "0" } else { kapi_no };
-                format!("{}{}-{}", letters, blok_no, kapi_no)
-            } else {
-                daire_no
+        let daire_no = match raw_daire_no.parse::<DaireNo>() {
+            Ok(daire_no) => daire_no,
+            Err(e) => {
+                report.push(i, raw_daire_no, RowStatus::Error, e.to_string());
+                continue;
             }
         };
+        seen.insert(daire_no.to_string());
 
-        let property = match db.get_property_by_daire_no(&daire_no).await {
-            Ok(Some(p)) => p,
-            Ok(None) => {
-                eprintln!("{} bulunamadı, atlanıyor.", daire_no);
-                skipped += 1;
-                continue;
+        // Deneme çalıştırmasında da değişiklikler yapılır, işlem sonunda geri alınır
+        runner.begin_row().await?;
+        let session = &mut runner.session;
+        let result = async {
+            let Some(property) = session.get_property_by_daire_no(site_id, &daire_no).await? else {
+                return Ok(None);
+            };
+            let plan = plan_row(session, site_id, &property, get, options.sync).await?;
+            let owner_id = apply_row(session, site_id, &property, &plan).await?;
+            Ok::<_, sqlx::Error>(Some((plan, property.id, owner_id)))
+        }
+        .await;
+        runner.end_row(result.is_ok()).await?;
+
+        let kiraci_adi = get("kiraci_ad_soyad");
+        let unmatched = match &result {
+            Ok(Some((plan, ..))) => plan.unmatched_tenant.clone(),
+            Ok(None) => Some("Daire bulunamadı".to_string()),
+            Err(_) => None,
+        };
+        if let Some(reason) = unmatched.filter(|_| !kiraci_adi.is_empty()) {
+            report.unmatched_tenants.push(UnmatchedTenant {
+                row_number: i as i32 + 1,
+                daire_no: daire_no.to_string(),
+                ad_soyad: kiraci_adi,
+                reason,
+            });
+        }
+
+        match result {
+            Ok(Some((plan, property_id, owner_id))) => {
+                let unit = units.entry(daire_no.to_string()).or_insert(UnitOwners {
+                    property_id,
+                    row: i,
+                    owners: Vec::new(),
+                });
+                if let Some(id) = owner_id
+                    && !unit.owners.iter().any(|(o, _)| *o == id)
+                {
+                    let name = plan.owner.as_ref().map(|o| o.body.ad_soyad.clone());
+                    unit.owners.push((id, name.unwrap_or_default()));
+                }
+                report.push(i, daire_no.to_string(), plan.status(), plan.describe());
+            }
+            Ok(None) => report.push(i, daire_no.to_string(), RowStatus::Skip, "Daire bulunamadı"),
+            Err(e) => {
+                failed.insert(daire_no.to_string());
+                report.push(i, daire_no.to_string(), RowStatus::Error, e.to_string());
             }
+        }
+    }
+
+    // Eşitleme dışında dosyadaki sahipler mevcut sahiplerin yerini alır; sahibi yazılmamış
+    // dairelere yalnızca eşitlemede --unlink-missing ile dokunulur
+    let unlink = !options.sync || options.unlink_missing;
+    for (daire_no, unit) in &units {
+        if unit.owners.is_empty() && !options.sync {
+            continue;
+        }
+        // Satırlardan biri geri alındıysa sahip listesi eksiktir, dokunulmaz
+        if failed.contains(daire_no) {
+            report.amend(
+                unit.row,
+                RowStatus::Skip,
+                "Hatalı satır olduğu için sahiplik atanmadı",
+            );
+            continue;
+        }
+
+        runner.begin_row().await?;
+        let result = sync_owners(&mut runner.session, unit, unlink).await;
+        runner.end_row(result.is_ok()).await?;
+
+        let diff = match result {
+            Ok(diff) => diff,
             Err(e) => {
-                eprintln!("{} sorgulanırken hata: {}", daire_no, e);
-                skipped += 1;
+                report.amend(unit.row, RowStatus::Error, &e.to_string());
                 continue;
             }
         };
+        if !diff.added.is_empty() {
+            let message = format!("Yeni sahip: {}", diff.added.join(", "));
+            report.amend(unit.row, RowStatus::Update, &message);
+        }
+        if !diff.removed.is_empty() {
+            let message = format!("Sahiplikten çıkarıldı: {}", diff.removed.join(", "));
+            report.amend(unit.row, RowStatus::Update, &message);
+            if options.sync {
+                report.missing.push(MissingRecord {
+                    row_key: daire_no.clone(),
+                    message: format!(
+                        "Sahip dosyada yok, sahiplik sonlandırıldı: {}",
+                        diff.removed.join(", ")
+                    ),
+                });
+            }
+        }
+        if !diff.kept.is_empty() {
+            let message = format!("Dosyada olmayan sahip korunuyor: {}", diff.kept.join(", "));
+            report.amend(unit.row, RowStatus::Skip, &message);
+            report.missing.push(MissingRecord {
+                row_key: daire_no.clone(),
+                message: format!(
+                    "Sahip dosyada yok, sahiplik korunuyor: {}",
+                    diff.kept.join(", ")
+                ),
+            });
+        }
+    }
 
-        let sahip_adi = get(7);
-        if !sahip_adi.is_empty() {
-            let customer = db
-                .get_or_create_customer(
-                    &sahip_adi,
-                    &get(8),  // gsm
-                    &get(9),  // telefon
-                    &get(10), // email
-                    &get(11), // acil_kisi
-                    &get(12), // uyruk
-                )
-                .await;
-
-            match customer {
-                Ok(c) => {
-                    if let Err(e) = db.set_property_owner(property.id, c.id).await {
-                        eprintln!("{} sahibi atanırken hata: {}", daire_no, e);
-                    }
-                }
-                Err(e) => eprintln!("{} müşteri oluşturulurken hata: {}", daire_no, e),
+    if options.sync {
+        // Dosyada hiç geçmeyen dairelerin sahipleri yalnızca raporlanır
+        for (_, daire_no, owners) in runner.session.list_site_properties(site_id).await? {
+            if let Some(owners) = owners
+                && !seen.contains(&daire_no)
+            {
+                report.missing.push(MissingRecord {
+                    row_key: daire_no,
+                    message: format!("Daire dosyada yok, sahiplik korunuyor: {}", owners),
+                });
             }
         }
+    }
+
+    runner.progress(total_rows, &report);
+    runner.finish(&mut report).await?;
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owner(n: u128, name: &str) -> (Uuid, String) {
+        (Uuid::from_u128(n), name.to_string())
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn unchanged_owners_produce_no_diff() {
+        let current = [owner(1, "Ali A"), owner(2, "Ayşe B")];
+        let file = [owner(2, "Ayşe B"), owner(1, "Ali A")];
 
-        let kiraci_adi = get(13);
-        if !kiraci_adi.is_empty()
-            && let Err(e) = db.set_kiraci_var_mi(property.id, true).await
-        {
-            eprintln!("{} kiraci_var_mi güncellenirken hata: {}", daire_no, e);
+        for unlink in [false, true] {
+            let diff = owner_diff(&current, &file, unlink);
+            assert!(!diff.changed());
+            assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.kept.is_empty());
         }
+    }
+
+    #[test]
+    fn keeps_missing_owners_without_unlink() {
+        let current = [owner(1, "Ali A"), owner(2, "Ayşe B")];
+        let file = [owner(1, "Ali A"), owner(3, "Can C")];
 
-        created += 1;
+        let diff = owner_diff(&current, &file, false);
+        assert_eq!(
+            diff,
+            OwnerDiff {
+                owners: vec![Uuid::from_u128(1), Uuid::from_u128(3), Uuid::from_u128(2)],
+                added: names(&["Can C"]),
+                removed: Vec::new(),
+                kept: names(&["Ayşe B"]),
+            }
+        );
+        assert!(diff.changed());
     }
 
-    println!("{} kayıt işlendi, {} atlandı.", created, skipped);
+    #[test]
+    fn removes_missing_owners_with_unlink() {
+        let current = [owner(1, "Ali A"), owner(2, "Ayşe B")];
+        let file = [owner(1, "Ali A"), owner(3, "Can C")];
+
+        let diff = owner_diff(&current, &file, true);
+        assert_eq!(
+            diff,
+            OwnerDiff {
+                owners: vec![Uuid::from_u128(1), Uuid::from_u128(3)],
+                added: names(&["Can C"]),
+                removed: names(&["Ayşe B"]),
+                kept: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn only_kept_owners_are_not_a_change() {
+        let current = [owner(1, "Ali A"), owner(2, "Ayşe B")];
+        let file = [owner(1, "Ali A")];
+
+        let diff = owner_diff(&current, &file, false);
+        assert!(!diff.changed());
+        assert_eq!(diff.kept, names(&["Ayşe B"]));
+
+        let diff = owner_diff(&current, &file, true);
+        assert!(diff.changed());
+        assert_eq!(diff.owners, vec![Uuid::from_u128(1)]);
+        assert_eq!(diff.removed, names(&["Ayşe B"]));
+    }
+
+    #[test]
+    fn empty_file_owners_unlink_everyone() {
+        let current = [owner(1, "Ali A")];
+
+        let diff = owner_diff(&current, &[], true);
+        assert!(diff.changed());
+        assert!(diff.owners.is_empty());
+        assert_eq!(diff.removed, names(&["Ali A"]));
+
+        let diff = owner_diff(&current, &[], false);
+        assert!(!diff.changed());
+        assert_eq!(diff.owners, vec![Uuid::from_u128(1)]);
+    }
+
+    #[test]
+    fn new_unit_adds_all_owners() {
+        let file = [owner(1, "Ali A"), owner(2, "Ayşe B")];
+
+        let diff = owner_diff(&[], &file, false);
+        assert_eq!(diff.added, names(&["Ali A", "Ayşe B"]));
+        assert_eq!(diff.owners, vec![Uuid::from_u128(1), Uuid::from_u128(2)]);
+        assert!(diff.removed.is_empty() && diff.kept.is_empty());
+    }
 }
@@ -1,8 +1,16 @@
+use crate::commands::columns::{ColumnMap, ColumnSpec, resolve_sheet};
+use crate::commands::import_report::{ImportReport, NO_CHANGES, RowStatus};
+use crate::commands::import_run::{
+    ImportContext, ImportError, ImportFile, ImportRunner, print_result,
+};
+use crate::commands::{ImportKind, ImportOptions};
 use crate::database::Database;
-use crate::models::CreateProperty;
+use crate::models::{Cephe, CreateProperty, DaireNo, MissingRecord, Property};
 use bigdecimal::BigDecimal;
-use calamine::{Data, Reader, Xlsx, open_workbook};
+use calamine::Data;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use uuid::Uuid;
 
 fn parse_cephe(s: &str) -> Option<crate::models::Cephe> {
     match s.trim() {
@@ -16,76 +24,356 @@ fn parse_cephe(s: &str) -> Option<crate::models::Cephe> {
     }
 }
 
-pub async fn run(db: &Database, filepath: &str) {
-    let mut workbook: Xlsx<_> = open_workbook(filepath).expect("Dosya açılamadı");
-    let sheet = workbook.worksheet_range_at(0).unwrap().unwrap();
-
-    let mut created = 0;
-    let mut updated = 0;
-    let mut errors = 0;
-
-    for row in sheet.rows() {
-        let get_str = |i: usize| -> Option<String> {
-            row.get(i).and_then(|v| match v {
-                Data::String(s) => Some(s.trim().to_string()),
-                Data::Float(f) => Some(f.to_string()),
-                Data::Int(i) => Some(i.to_string()),
-                _ => None,
-            })
-        };
+const COLUMNS: &[ColumnSpec] = &[
+    ColumnSpec {
+        field: "blok",
+        aliases: &["Blok", "Blok Adı"],
+        required: true,
+    },
+    ColumnSpec {
+        field: "kat",
+        aliases: &["Kat", "Kat No"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "kapi_no",
+        aliases: &["Kapı No", "KapiNo", "Kapı Numarası", "Kapı"],
+        required: true,
+    },
+    ColumnSpec {
+        field: "oda_sayisi",
+        aliases: &["Oda Sayısı", "Oda", "OdaSayisi"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "daire_tipi",
+        aliases: &["Daire Tipi", "Tip", "DaireTipi"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "brut_m2",
+        aliases: &["Brüt m2", "Brüt", "Brüt Alan", "BrutM2"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "net_m2",
+        aliases: &["Net m2", "Net", "Net Alan", "NetM2"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "balkon_m2",
+        aliases: &["Balkon m2", "Balkon", "BalkonM2"],
+        required: false,
+    },
+    ColumnSpec {
+        field: "cephe",
+        aliases: &["Cephe", "Yön"],
+        required: false,
+    },
+];
 
-        let get_decimal = |i: usize| -> Option<BigDecimal> {
-            row.get(i).and_then(|v| match v {
-                Data::Float(f) => BigDecimal::from_str(&f.to_string()).ok(),
-                Data::Int(i) => BigDecimal::from_str(&i.to_string()).ok(),
-                _ => None,
-            })
-        };
+fn get_str(cell: Option<&Data>) -> Option<String> {
+    cell.and_then(|v| match v {
+        Data::String(s) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+        Data::Float(f) => Some(f.to_string()),
+        Data::Int(i) => Some(i.to_string()),
+        _ => None,
+    })
+}
 
-        let blok = match get_str(1) {
-            Some(b) => b,
-            None => continue,
-        };
+fn get_decimal(cell: Option<&Data>) -> Option<BigDecimal> {
+    cell.and_then(|v| match v {
+        Data::String(s) => BigDecimal::from_str(&s.trim().replace(',', ".")).ok(),
+        Data::Float(f) => BigDecimal::from_str(&f.to_string()).ok(),
+        Data::Int(i) => BigDecimal::from_str(&i.to_string()).ok(),
+        _ => None,
+    })
+}
 
-        let kapi_no: i32 = match get_str(3).and_then(|v| v.parse().ok()) {
-            Some(k) => k,
-            None => continue,
-        };
+struct ParsedProperty {
+    daire_no: DaireNo,
+    kat: String,
+    oda_sayisi: String,
+    daire_tipi: String,
+    brut_m2: BigDecimal,
+    net_m2: BigDecimal,
+    balkon_m2: Option<BigDecimal>,
+    cephe: Option<crate::models::Cephe>,
+    warnings: Vec<String>,
+}
 
-        let daire_no = format!("{}{}", blok, kapi_no);
-
-        let prop = CreateProperty {
-            daire_no,
-            blok: blok.trim_end_matches('-').to_string(),
-            kat: get_str(2).unwrap_or_default(),
-            kapi_no,
-            oda_sayisi: get_str(4).unwrap_or_default(),
-            daire_tipi: get_str(5).unwrap_or_default(),
-            brut_m2: get_decimal(6).unwrap_or_default(),
-            net_m2: get_decimal(7).unwrap_or_default(),
-            balkon_m2: get_decimal(8),
-            cephe: get_str(9).and_then(|s| parse_cephe(&s)),
-            kiraci_var_mi: false,
-            sahip_id: None,
-        };
+impl ParsedProperty {
+    fn to_property(&self, block_id: Uuid) -> CreateProperty {
+        CreateProperty {
+            daire_no: self.daire_no.to_string(),
+            block_id,
+            kat: self.kat.clone(),
+            kapi_no: self.daire_no.kapi_no(),
+            oda_sayisi: self.oda_sayisi.clone(),
+            daire_tipi: self.daire_tipi.clone(),
+            brut_m2: self.brut_m2.clone(),
+            net_m2: self.net_m2.clone(),
+            balkon_m2: self.balkon_m2.clone(),
+            cephe: self.cephe,
+        }
+    }
+}
+
+// Eşitlemede dosyadaki değerlerin kayıttakinden farkları, örn. "Kat: 1 → 2"
+fn property_changes(existing: &Property, parsed: &ParsedProperty) -> Vec<String> {
+    let mut changes = Vec::new();
+    let mut compare = |label: &str, changed: bool, old: String, new: String| {
+        if changed {
+            changes.push(format!("{}: {} → {}", label, old, new));
+        }
+    };
+    let decimal = |value: Option<&BigDecimal>| match value {
+        Some(v) => v.with_scale(2).to_string(),
+        None => "(boş)".to_string(),
+    };
+    let cephe = |value: Option<Cephe>| value.map_or("(boş)", Cephe::as_str).to_string();
+
+    compare(
+        "Blok",
+        existing.blok != parsed.daire_no.blok(),
+        existing.blok.clone(),
+        parsed.daire_no.blok().to_string(),
+    );
+    compare(
+        "Kat",
+        existing.kat != parsed.kat,
+        existing.kat.clone(),
+        parsed.kat.clone(),
+    );
+    compare(
+        "Oda sayısı",
+        existing.oda_sayisi != parsed.oda_sayisi,
+        existing.oda_sayisi.clone(),
+        parsed.oda_sayisi.clone(),
+    );
+    compare(
+        "Daire tipi",
+        existing.daire_tipi != parsed.daire_tipi,
+        existing.daire_tipi.clone(),
+        parsed.daire_tipi.clone(),
+    );
+    compare(
+        "Brüt m²",
+        existing.brut_m2 != parsed.brut_m2,
+        decimal(Some(&existing.brut_m2)),
+        decimal(Some(&parsed.brut_m2)),
+    );
+    compare(
+        "Net m²",
+        existing.net_m2 != parsed.net_m2,
+        decimal(Some(&existing.net_m2)),
+        decimal(Some(&parsed.net_m2)),
+    );
+    compare(
+        "Balkon m²",
+        existing.balkon_m2 != parsed.balkon_m2,
+        decimal(existing.balkon_m2.as_ref()),
+        decimal(parsed.balkon_m2.as_ref()),
+    );
+    compare(
+        "Cephe",
+        existing.cephe != parsed.cephe,
+        cephe(existing.cephe),
+        cephe(parsed.cephe),
+    );
+
+    changes
+}
+
+// Ok(None): satır boş, raporlanmaz
+fn parse_row(row: &[Data], cols: &ColumnMap) -> Result<Option<ParsedProperty>, (String, String)> {
+    if row.iter().all(|c| matches!(c, Data::Empty)) {
+        return Ok(None);
+    }
+
+    let blok = get_str(cols.cell(row, "blok")).ok_or((String::new(), "Blok boş".to_string()))?;
+    let kapi_no: i32 = get_str(cols.cell(row, "kapi_no"))
+        .and_then(|v| v.parse().ok())
+        .ok_or((blok.clone(), "Kapı no geçersiz".to_string()))?;
+    let daire_no = DaireNo::new(&blok, kapi_no).map_err(|e| (blok.clone(), e.to_string()))?;
 
-        match db.upsert_property(&prop).await {
-            Ok(is_new) => {
-                if is_new {
-                    created += 1;
+    let mut warnings = Vec::new();
+    let brut_m2 = get_decimal(cols.cell(row, "brut_m2")).unwrap_or_else(|| {
+        warnings.push("Brüt m² boş, 0 kabul edildi".to_string());
+        BigDecimal::default()
+    });
+    let net_m2 = get_decimal(cols.cell(row, "net_m2")).unwrap_or_else(|| {
+        warnings.push("Net m² boş, 0 kabul edildi".to_string());
+        BigDecimal::default()
+    });
+    let cephe = match get_str(cols.cell(row, "cephe")) {
+        Some(s) => {
+            let cephe = parse_cephe(&s);
+            if cephe.is_none() {
+                warnings.push(format!("Cephe tanınmadı: {}", s));
+            }
+            cephe
+        }
+        None => None,
+    };
+
+    Ok(Some(ParsedProperty {
+        daire_no,
+        kat: get_str(cols.cell(row, "kat")).unwrap_or_default(),
+        oda_sayisi: get_str(cols.cell(row, "oda_sayisi")).unwrap_or_default(),
+        daire_tipi: get_str(cols.cell(row, "daire_tipi")).unwrap_or_default(),
+        brut_m2,
+        net_m2,
+        balkon_m2: get_decimal(cols.cell(row, "balkon_m2")),
+        cephe,
+        warnings,
+    }))
+}
+
+pub async fn run(db: &Database, filepath: &str, site: &str, options: &ImportOptions) {
+    let file = match ImportFile::read(filepath) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+    let ctx = match ImportContext::for_cli(db, site, options).await {
+        Ok(ctx) => ctx,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    let result = import(db, &file, ctx, options).await;
+    print_result(result, &file, options);
+}
+
+pub async fn import(
+    db: &Database,
+    file: &ImportFile,
+    ctx: ImportContext<'_>,
+    options: &ImportOptions,
+) -> Result<ImportReport, ImportError> {
+    let sheet = file.sheet(&options.source)?;
+    let cols = resolve_sheet(&sheet, COLUMNS, options.columns_path.as_deref())?;
+    let total_rows = sheet.height().saturating_sub(1);
+
+    let mut report = ImportReport::new(options.dry_run);
+    let mut runner = ImportRunner::begin(
+        db,
+        ImportKind::Properties,
+        file,
+        ctx,
+        options,
+        &mut report,
+        total_rows,
+    )
+    .await?;
+    let site_id = runner.site_id;
+    let mut blocks: HashMap<String, Uuid> = HashMap::new();
+    let mut seen: HashSet<String> = HashSet::new();
+
+    for (i, row) in sheet.rows().enumerate().skip(1) {
+        runner.progress(i - 1, &report);
+
+        let parsed = match parse_row(row, &cols) {
+            Ok(Some(p)) => p,
+            Ok(None) => continue,
+            Err((key, message)) => {
+                let status = if key.is_empty() {
+                    RowStatus::Skip
                 } else {
-                    updated += 1;
+                    RowStatus::Error
+                };
+                report.push(i, key, status, message);
+                continue;
+            }
+        };
+
+        let mut notes = parsed.warnings.clone();
+        seen.insert(parsed.daire_no.to_string());
+
+        runner.begin_row().await?;
+        let session = &mut runner.session;
+        let result = async {
+            // Eşitlemede değişmeyen daireye yazılmaz
+            if options.sync
+                && let Some(existing) = session
+                    .get_property_by_daire_no(site_id, &parsed.daire_no)
+                    .await?
+            {
+                let changes = property_changes(&existing, &parsed);
+                if changes.is_empty() {
+                    return Ok(None);
                 }
+                notes.push(format!("Değişen: {}", changes.join(", ")));
+            }
+
+            // Blok önbelleğe satır başarılı olunca eklenir; geri alınan blok yeniden kullanılmaz
+            let block_id = match blocks.get(parsed.daire_no.blok()) {
+                Some(id) => *id,
+                None => {
+                    let (id, created) = session
+                        .ensure_block(site_id, parsed.daire_no.blok())
+                        .await?;
+                    if created {
+                        notes.push(format!("Yeni blok: {}", parsed.daire_no.blok()));
+                    }
+                    id
+                }
+            };
+            let created = session
+                .upsert_property(&parsed.to_property(block_id))
+                .await?;
+            Ok::<_, sqlx::Error>(Some((block_id, created)))
+        }
+        .await;
+        runner.end_row(result.is_ok()).await?;
+
+        match result {
+            Ok(Some((block_id, created))) => {
+                blocks.insert(parsed.daire_no.blok().to_string(), block_id);
+                let status = if created {
+                    RowStatus::Create
+                } else {
+                    RowStatus::Update
+                };
+                report.push(i, parsed.daire_no.to_string(), status, notes.join("; "));
             }
-            Err(e) => {
-                eprintln!("Hata — daire_no {}: {}", prop.daire_no, e);
-                errors += 1;
+            Ok(None) => {
+                notes.insert(0, NO_CHANGES.to_string());
+                report.push(
+                    i,
+                    parsed.daire_no.to_string(),
+                    RowStatus::Skip,
+                    notes.join("; "),
+                );
             }
+            Err(e) => report.push(
+                i,
+                parsed.daire_no.to_string(),
+                RowStatus::Error,
+                e.to_string(),
+            ),
         }
     }
 
-    println!(
-        "{} oluşturuldu, {} güncellendi, {} hata",
-        created, updated, errors
-    );
+    // Dosyada olmayan daireler yalnızca raporlanır, silinmez
+    if options.sync {
+        for (_, daire_no, _) in runner.session.list_site_properties(site_id).await? {
+            if !seen.contains(&daire_no) {
+                report.missing.push(MissingRecord {
+                    row_key: daire_no,
+                    message: "Daire dosyada yok; kayıt korunuyor".to_string(),
+                });
+            }
+        }
+    }
+
+    runner.progress(total_rows, &report);
+    runner.finish(&mut report).await?;
+
+    Ok(report)
 }
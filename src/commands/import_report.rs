@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use calamine::{Data, Range};
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+use crate::models::{MissingRecord, UnmatchedTenant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowStatus {
+    Create,
+    Update,
+    Skip,
+    Error,
+}
+
+impl RowStatus {
+    pub fn label(self, dry_run: bool) -> &'static str {
+        match (self, dry_run) {
+            (RowStatus::Create, true) => "Oluşturulacak",
+            (RowStatus::Create, false) => "Oluşturuldu",
+            (RowStatus::Update, true) => "Güncellenecek",
+            (RowStatus::Update, false) => "Güncellendi",
+            (RowStatus::Skip, true) => "Atlanacak",
+            (RowStatus::Skip, false) => "Atlandı",
+            (RowStatus::Error, _) => "Hata",
+        }
+    }
+}
+
+// Değişiklik içermeyen satırların açıklaması
+pub const NO_CHANGES: &str = "Değişiklik yok";
+
+pub struct RowReport {
+    // Sayfadaki 0 tabanlı satır indeksi
+    pub row: usize,
+    pub key: String,
+    pub status: RowStatus,
+    pub message: String,
+}
+
+pub struct ImportReport {
+    pub dry_run: bool,
+    // Tümü ya da hiçbiri kipinde hata çıkınca değişiklikler geri alınır
+    pub rolled_back: bool,
+    // Aynı dosyanın önceki başarılı içe aktarılma zamanı
+    pub previous_import: Option<String>,
+    pub rows: Vec<RowReport>,
+    // Müşteri dosyasında daireye bağlanamayan kiracılar
+    pub unmatched_tenants: Vec<UnmatchedTenant>,
+    // Eşitlemede veritabanında olup dosyada bulunmayan kayıtlar
+    pub missing: Vec<MissingRecord>,
+}
+
+impl ImportReport {
+    pub fn new(dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            rolled_back: false,
+            previous_import: None,
+            rows: Vec::new(),
+            unmatched_tenants: Vec::new(),
+            missing: Vec::new(),
+        }
+    }
+
+    // Kaydedilmeyen değişiklikler "yapılacak" olarak etiketlenir
+    fn pending(&self) -> bool {
+        self.dry_run || self.rolled_back
+    }
+
+    pub fn push(
+        &mut self,
+        row: usize,
+        key: impl Into<String>,
+        status: RowStatus,
+        message: impl Into<String>,
+    ) {
+        self.rows.push(RowReport {
+            row,
+            key: key.into(),
+            status,
+            message: message.into(),
+        });
+    }
+
+    // Eşitlemede satır işlendikten sonra bulunan değişiklikleri aynı satıra ekler
+    pub fn amend(&mut self, row: usize, status: RowStatus, message: &str) {
+        let Some(report) = self.rows.iter_mut().find(|r| r.row == row) else {
+            return;
+        };
+        if status == RowStatus::Error || report.status == RowStatus::Skip {
+            report.status = status;
+        }
+        if report.message.is_empty() || report.message == NO_CHANGES {
+            report.message = message.to_string();
+        } else {
+            report.message = format!("{}; {}", report.message, message);
+        }
+    }
+
+    pub fn count(&self, status: RowStatus) -> usize {
+        self.rows.iter().filter(|r| r.status == status).count()
+    }
+
+    pub fn print(&self) {
+        if let Some(at) = &self.previous_import {
+            println!("Bu dosya {} tarihinde içe aktarılmış.", at);
+        }
+        for r in &self.rows {
+            let key = if r.key.is_empty() { "-" } else { &r.key };
+            if r.message.is_empty() {
+                println!(
+                    "Satır {} [{}]: {}",
+                    r.row + 1,
+                    key,
+                    r.status.label(self.pending())
+                );
+            } else {
+                println!(
+                    "Satır {} [{}]: {} — {}",
+                    r.row + 1,
+                    key,
+                    r.status.label(self.pending()),
+                    r.message
+                );
+            }
+        }
+        self.print_summary();
+    }
+
+    pub fn print_summary(&self) {
+        if !self.missing.is_empty() {
+            println!("Dosyada bulunmayan kayıtlar:");
+            for m in &self.missing {
+                println!("  [{}] {}", m.row_key, m.message);
+            }
+        }
+        if !self.unmatched_tenants.is_empty() {
+            println!("Eşleşmeyen kiracılar:");
+            for t in &self.unmatched_tenants {
+                println!(
+                    "  Satır {} [{}]: {} — {}",
+                    t.row_number, t.daire_no, t.ad_soyad, t.reason
+                );
+            }
+        }
+        if self.dry_run {
+            println!("Deneme çalıştırması, veritabanına yazılmadı.");
+        } else if self.rolled_back {
+            println!("Hatalı satırlar olduğu için hiçbir değişiklik kaydedilmedi.");
+        }
+        println!(
+            "{} {}, {} {}, {} {}, {} hata",
+            self.count(RowStatus::Create),
+            RowStatus::Create.label(self.pending()).to_lowercase(),
+            self.count(RowStatus::Update),
+            RowStatus::Update.label(self.pending()).to_lowercase(),
+            self.count(RowStatus::Skip),
+            RowStatus::Skip.label(self.pending()).to_lowercase(),
+            self.count(RowStatus::Error),
+        );
+    }
+
+    // Kaynak sayfayı aynen kopyalar, sonuna Durum ve Açıklama sütunlarını ekler
+    pub fn write_xlsx(
+        &self,
+        path: &str,
+        sheet: &Range<Data>,
+        header_row: Option<usize>,
+    ) -> Result<(), XlsxError> {
+        let by_row: HashMap<usize, &RowReport> = self.rows.iter().map(|r| (r.row, r)).collect();
+        let (start_row, start_col) = sheet.start().unwrap_or((0, 0));
+        let width = sheet.width() as u16;
+        let bold = Format::new().set_bold();
+
+        let mut workbook = Workbook::new();
+        let out = workbook.add_worksheet();
+        out.set_name("Rapor")?;
+
+        for (i, row) in sheet.rows().enumerate() {
+            let r = i as u32 + start_row;
+            for (j, cell) in row.iter().enumerate() {
+                let c = j as u16 + start_col as u16;
+                match cell {
+                    Data::String(s) => out.write_string(r, c, s)?,
+                    Data::Float(f) => out.write_number(r, c, *f)?,
+                    Data::Int(n) => out.write_number(r, c, *n as f64)?,
+                    Data::Bool(b) => out.write_boolean(r, c, *b)?,
+                    Data::DateTime(d) => out.write_number(r, c, d.as_f64())?,
+                    Data::Empty => out,
+                    other => out.write_string(r, c, other.to_string())?,
+                };
+            }
+
+            let status_col = start_col as u16 + width;
+            if header_row == Some(i) {
+                out.write_string_with_format(r, status_col, "Durum", &bold)?;
+                out.write_string_with_format(r, status_col + 1, "Açıklama", &bold)?;
+            } else if let Some(report) = by_row.get(&i) {
+                out.write_string(r, status_col, report.status.label(self.pending()))?;
+                out.write_string(r, status_col + 1, &report.message)?;
+            }
+        }
+
+        workbook.save(path)
+    }
+}
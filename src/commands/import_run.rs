@@ -0,0 +1,265 @@
+use std::path::Path;
+
+use calamine::{Data, Range};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::commands::columns::ColumnError;
+use crate::commands::import_report::{ImportReport, RowStatus};
+use crate::commands::sheet::{SheetOptions, read_sheet};
+use crate::commands::{ImportKind, ImportMode, ImportOptions};
+use crate::database::{Database, ImportSession};
+use crate::models::{ImportRunError, NewImportRun};
+
+const START_SAVEPOINT: &str = "import_start";
+const ROW_SAVEPOINT: &str = "import_row";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportError {
+    #[error("Dosya okunamadı: {0}")]
+    File(String),
+    #[error(transparent)]
+    Columns(#[from] ColumnError),
+    #[error(
+        "Bu dosya {0} tarihinde içe aktarılmış; yeniden içe aktarmak için force seçeneğini kullanın"
+    )]
+    AlreadyImported(String),
+    #[error("Veritabanı hatası: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+pub struct ImportFile {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl ImportFile {
+    pub fn read(path: &str) -> Result<Self, ImportError> {
+        let data = std::fs::read(path).map_err(|e| ImportError::File(e.to_string()))?;
+        let name = Path::new(path)
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string());
+        Ok(Self { name, data })
+    }
+
+    pub fn hash(&self) -> String {
+        format!("{:x}", Sha256::digest(&self.data))
+    }
+
+    pub fn sheet(&self, options: &SheetOptions) -> Result<Range<Data>, ImportError> {
+        read_sheet(&self.name, &self.data, options).map_err(ImportError::File)
+    }
+}
+
+pub enum ImportSite<'a> {
+    // Komut satırından: site yoksa oluşturulur
+    Name(&'a str),
+    Id(Uuid),
+}
+
+// İşlenen satır, toplam satır ve o ana kadarki rapor ile çağrılır
+pub type ProgressFn = Box<dyn Fn(usize, usize, &ImportReport) + Send + Sync>;
+
+pub struct ImportContext<'a> {
+    pub run_id: Uuid,
+    pub site: ImportSite<'a>,
+    pub user_id: Option<Uuid>,
+    pub progress: Option<ProgressFn>,
+}
+
+impl<'a> ImportContext<'a> {
+    pub async fn for_cli(
+        db: &Database,
+        site: &'a str,
+        options: &ImportOptions,
+    ) -> Result<ImportContext<'a>, String> {
+        let user_id = match &options.user_email {
+            Some(email) => Some(
+                db.find_user_by_email(email)
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .ok_or_else(|| format!("Kullanıcı bulunamadı: {}", email))?
+                    .id,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            run_id: Uuid::new_v4(),
+            site: ImportSite::Name(site),
+            user_id,
+            progress: None,
+        })
+    }
+}
+
+// Bir içe aktarma çalıştırmasını tek işlem içinde yürütür. Her satır kendi kayıt
+// noktasında çalışır; hatalı satır geri alınır ve rapor eksiksiz tamamlanır.
+pub struct ImportRunner {
+    pub session: ImportSession,
+    pub site_id: Uuid,
+    run_id: Uuid,
+    kind: ImportKind,
+    file_name: String,
+    file_hash: String,
+    user_id: Option<Uuid>,
+    started_at: chrono::DateTime<chrono::Utc>,
+    dry_run: bool,
+    sync: bool,
+    mode: ImportMode,
+    progress: Option<ProgressFn>,
+    total_rows: usize,
+}
+
+impl ImportRunner {
+    // Dosya daha önce içe aktarıldıysa --force verilmedikçe AlreadyImported döner
+    pub async fn begin(
+        db: &Database,
+        kind: ImportKind,
+        file: &ImportFile,
+        ctx: ImportContext<'_>,
+        options: &ImportOptions,
+        report: &mut ImportReport,
+        total_rows: usize,
+    ) -> Result<Self, ImportError> {
+        let file_hash = file.hash();
+        let mut session = db.begin_import().await?;
+        let site_id = match ctx.site {
+            ImportSite::Name(name) => session.ensure_site(name).await?,
+            ImportSite::Id(id) => id,
+        };
+
+        if let Some(previous) = session
+            .find_committed_run(site_id, kind.as_str(), &file_hash)
+            .await?
+        {
+            let finished_at = crate::timezone::local(previous.finished_at)
+                .format("%d.%m.%Y %H:%M")
+                .to_string();
+            if !options.force && !options.dry_run {
+                session.rollback().await?;
+                return Err(ImportError::AlreadyImported(finished_at));
+            }
+            report.previous_import = Some(finished_at);
+        }
+
+        session.savepoint(START_SAVEPOINT).await?;
+
+        Ok(Self {
+            session,
+            site_id,
+            run_id: ctx.run_id,
+            kind,
+            file_name: file.name.clone(),
+            file_hash,
+            user_id: ctx.user_id,
+            started_at: chrono::Utc::now(),
+            dry_run: options.dry_run,
+            sync: options.sync,
+            mode: options.mode,
+            progress: ctx.progress,
+            total_rows,
+        })
+    }
+
+    pub async fn begin_row(&mut self) -> Result<(), sqlx::Error> {
+        self.session.savepoint(ROW_SAVEPOINT).await
+    }
+
+    pub async fn end_row(&mut self, ok: bool) -> Result<(), sqlx::Error> {
+        if ok {
+            self.session.release_savepoint(ROW_SAVEPOINT).await
+        } else {
+            self.session.rollback_to_savepoint(ROW_SAVEPOINT).await
+        }
+    }
+
+    pub fn progress(&self, processed: usize, report: &ImportReport) {
+        if let Some(progress) = &self.progress {
+            progress(processed, self.total_rows, report);
+        }
+    }
+
+    // Deneme çalıştırması tamamen geri alınır; gerçek çalıştırma import_runs'a kaydedilir
+    pub async fn finish(mut self, report: &mut ImportReport) -> Result<(), sqlx::Error> {
+        if self.dry_run {
+            return self.session.rollback().await;
+        }
+
+        if self.mode == ImportMode::AllOrNothing && report.count(RowStatus::Error) > 0 {
+            self.session.rollback_to_savepoint(START_SAVEPOINT).await?;
+            report.rolled_back = true;
+        }
+
+        let errors: Vec<ImportRunError> = report
+            .rows
+            .iter()
+            .filter(|r| r.status == RowStatus::Error)
+            .map(|r| ImportRunError {
+                row_number: r.row as i32 + 1,
+                row_key: r.key.clone(),
+                message: r.message.clone(),
+            })
+            .collect();
+
+        let run = NewImportRun {
+            id: self.run_id,
+            site_id: self.site_id,
+            kind: self.kind.as_str(),
+            file_name: self.file_name,
+            file_hash: self.file_hash,
+            mode: self.mode.as_str(),
+            status: if report.rolled_back {
+                "rolled_back"
+            } else {
+                "committed"
+            },
+            created_count: report.count(RowStatus::Create) as i32,
+            updated_count: report.count(RowStatus::Update) as i32,
+            skipped_count: report.count(RowStatus::Skip) as i32,
+            error_count: errors.len() as i32,
+            sync: self.sync,
+            missing_count: report.missing.len() as i32,
+            user_id: self.user_id,
+            started_at: self.started_at,
+        };
+
+        self.session
+            .create_import_run(&run, &errors, &report.unmatched_tenants, &report.missing)
+            .await?;
+        self.session.commit().await
+    }
+}
+
+// Komut satırı çalıştırmalarının sonucunu yazdırır ve istenirse rapor dosyasını oluşturur
+pub fn print_result(
+    result: Result<ImportReport, ImportError>,
+    file: &ImportFile,
+    options: &ImportOptions,
+) {
+    let report = match result {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    report.print();
+
+    if let Some(path) = &options.report_path {
+        let written = file
+            .sheet(&options.source)
+            .map_err(|e| e.to_string())
+            .and_then(|sheet| {
+                report
+                    .write_xlsx(path, &sheet, Some(0))
+                    .map_err(|e| e.to_string())
+            });
+        match written {
+            Ok(()) => println!("Rapor yazıldı: {}", path),
+            Err(e) => eprintln!("Rapor yazılamadı: {}", e),
+        }
+    }
+}
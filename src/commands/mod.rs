@@ -1,2 +1,114 @@
+pub mod columns;
+pub mod export;
 pub mod import_customers;
 pub mod import_properties;
+pub mod import_report;
+pub mod import_run;
+pub mod sheet;
+
+use sheet::SheetOptions;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    Properties,
+    Customers,
+}
+
+impl ImportKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ImportKind::Properties => "properties",
+            ImportKind::Customers => "customers",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "properties" => Some(ImportKind::Properties),
+            "customers" => Some(ImportKind::Customers),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    // Tek bir satır hatalıysa hiçbir değişiklik kaydedilmez
+    AllOrNothing,
+    // Hatalı satırlar geri alınır, diğerleri kaydedilir
+    BestEffort,
+}
+
+impl ImportMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ImportMode::AllOrNothing => "all_or_nothing",
+            ImportMode::BestEffort => "best_effort",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "all_or_nothing" => Some(ImportMode::AllOrNothing),
+            "best_effort" => Some(ImportMode::BestEffort),
+            _ => None,
+        }
+    }
+}
+
+pub struct ImportOptions {
+    pub dry_run: bool,
+    pub report_path: Option<String>,
+    pub mode: ImportMode,
+    // Daha önce içe aktarılmış dosyayı yeniden işler
+    pub force: bool,
+    pub user_email: Option<String>,
+    // Alan → başlık eşleme dosyası (TOML veya JSON)
+    pub columns_path: Option<String>,
+    pub source: SheetOptions,
+    // Dosya mevcut verilerle karşılaştırılır, yalnızca farklar yazılır
+    pub sync: bool,
+    // Eşitlemede dosyadaki dairelerde listelenmeyen sahiplerin bağı kaldırılır
+    pub unlink_missing: bool,
+}
+
+// Komut satırındaki içe aktarma bayraklarını konum argümanlarından ayırır
+pub fn parse_import_args(args: &[String]) -> (Vec<String>, ImportOptions) {
+    let mut positional = Vec::new();
+    let mut options = ImportOptions {
+        dry_run: false,
+        report_path: None,
+        mode: ImportMode::AllOrNothing,
+        force: false,
+        user_email: None,
+        columns_path: None,
+        source: SheetOptions::default(),
+        sync: false,
+        unlink_missing: false,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--dry-run" => options.dry_run = true,
+            "--report" => options.report_path = iter.next().cloned(),
+            "--best-effort" => options.mode = ImportMode::BestEffort,
+            "--force" => options.force = true,
+            "--user" => options.user_email = iter.next().cloned(),
+            "--columns" => options.columns_path = iter.next().cloned(),
+            "--sheet" => options.source.sheet = iter.next().cloned(),
+            "--delimiter" => options.source.delimiter = iter.next().cloned(),
+            "--encoding" => options.source.encoding = iter.next().cloned(),
+            "--decimal-comma" => options.source.decimal_comma = Some(true),
+            "--decimal-point" => options.source.decimal_comma = Some(false),
+            "--sync" => options.sync = true,
+            "--unlink-missing" => {
+                options.sync = true;
+                options.unlink_missing = true;
+            }
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    (positional, options)
+}
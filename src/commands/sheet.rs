@@ -0,0 +1,307 @@
+use std::io::Cursor;
+use std::path::Path;
+
+use calamine::{Data, Range, Reader, open_workbook_auto_from_rs};
+use encoding_rs::Encoding;
+
+const WORKBOOK_EXTENSIONS: &[&str] = &["xlsx", "xlsm", "xlsb", "xls", "ods"];
+const CSV_EXTENSIONS: &[&str] = &["csv", "txt"];
+
+#[derive(Default, Clone)]
+pub struct SheetOptions {
+    // Çalışma kitabındaki sayfa adı; verilmezse ilk sayfa okunur
+    pub sheet: Option<String>,
+    // CSV ayırıcısı (";", ",", "tab"); verilmezse ilk satırdan tahmin edilir
+    pub delimiter: Option<String>,
+    // CSV karakter kodlaması, örn. windows-1254; varsayılan UTF-8
+    pub encoding: Option<String>,
+    // CSV'de ondalık ayırıcı virgül mü; verilmezse ayırıcı ";" ise virgül kabul edilir
+    pub decimal_comma: Option<bool>,
+}
+
+fn extension(file_name: &str) -> String {
+    Path::new(file_name)
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+pub fn is_supported(file_name: &str) -> bool {
+    let ext = extension(file_name);
+    WORKBOOK_EXTENSIONS.contains(&ext.as_str()) || CSV_EXTENSIONS.contains(&ext.as_str())
+}
+
+// Dosya uzantısına göre CSV ya da çalışma kitabı olarak okur
+pub fn read_sheet(
+    file_name: &str,
+    data: &[u8],
+    options: &SheetOptions,
+) -> Result<Range<Data>, String> {
+    let ext = extension(file_name);
+    if CSV_EXTENSIONS.contains(&ext.as_str()) {
+        return read_csv(data, options);
+    }
+    if !WORKBOOK_EXTENSIONS.contains(&ext.as_str()) {
+        return Err(format!("Desteklenmeyen dosya türü: {}", file_name));
+    }
+
+    let mut workbook = open_workbook_auto_from_rs(Cursor::new(data)).map_err(|e| e.to_string())?;
+    let names = workbook.sheet_names();
+    let name = match &options.sheet {
+        Some(name) if names.contains(name) => name.clone(),
+        Some(name) => {
+            return Err(format!(
+                "Sayfa bulunamadı: {} (mevcut sayfalar: {})",
+                name,
+                names.join(", ")
+            ));
+        }
+        None => names
+            .first()
+            .cloned()
+            .ok_or_else(|| "Çalışma sayfası yok".to_string())?,
+    };
+
+    workbook.worksheet_range(&name).map_err(|e| e.to_string())
+}
+
+fn parse_delimiter(value: &str) -> Result<u8, String> {
+    match value {
+        "tab" | "\\t" | "\t" => Ok(b'\t'),
+        v if v.len() == 1 && v.is_ascii() => Ok(v.as_bytes()[0]),
+        v => Err(format!("Geçersiz CSV ayırıcısı: {}", v)),
+    }
+}
+
+fn detect_delimiter(text: &str) -> u8 {
+    let first_line = text.lines().next().unwrap_or_default();
+    [b',', b'\t', b';']
+        .into_iter()
+        .max_by_key(|d| first_line.bytes().filter(|b| b == d).count())
+        .unwrap_or(b';')
+}
+
+fn read_csv(data: &[u8], options: &SheetOptions) -> Result<Range<Data>, String> {
+    let encoding = match &options.encoding {
+        Some(label) => Encoding::for_label(label.trim().as_bytes())
+            .ok_or_else(|| format!("Bilinmeyen karakter kodlaması: {}", label))?,
+        None => encoding_rs::UTF_8,
+    };
+    // BOM varsa kodlama ondan belirlenir
+    let (text, used, had_errors) = encoding.decode(data);
+    if had_errors {
+        return Err(format!(
+            "Dosya {} olarak okunamadı; karakter kodlamasını belirtin (örn. windows-1254)",
+            used.name()
+        ));
+    }
+
+    let delimiter = match &options.delimiter {
+        Some(value) => parse_delimiter(value)?,
+        None => detect_delimiter(&text),
+    };
+    let decimal_comma = options.decimal_comma.unwrap_or(delimiter == b';');
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .from_reader(text.as_bytes());
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| e.to_string())?;
+        rows.push(
+            record
+                .iter()
+                .map(|v| csv_cell(v, decimal_comma))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 {
+        return Ok(Range::empty());
+    }
+
+    let mut range = Range::new((0, 0), (rows.len() as u32 - 1, width as u32 - 1));
+    for (r, row) in rows.into_iter().enumerate() {
+        for (c, value) in row.into_iter().enumerate() {
+            range.set_value((r as u32, c as u32), value);
+        }
+    }
+    Ok(range)
+}
+
+fn csv_cell(value: &str, decimal_comma: bool) -> Data {
+    let value = value.trim();
+    if value.is_empty() {
+        return Data::Empty;
+    }
+    parse_number(value, decimal_comma).unwrap_or_else(|| Data::String(value.to_string()))
+}
+
+// "1.234,56" gibi sayılar çevrilir; baştaki sıfır korunmalı olduğundan "0555..." metin kalır
+fn parse_number(value: &str, decimal_comma: bool) -> Option<Data> {
+    let (group, decimal) = if decimal_comma {
+        ('.', ',')
+    } else {
+        (',', '.')
+    };
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", value),
+    };
+    let (int_part, frac_part) = match digits.split_once(decimal) {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (digits, None),
+    };
+
+    let groups: Vec<&str> = int_part.split(group).collect();
+    let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let head = groups[0];
+    if !groups.iter().all(|g| all_digits(g))
+        || (groups.len() > 1 && (head.len() > 3 || groups[1..].iter().any(|g| g.len() != 3)))
+        || (head.starts_with('0') && (head.len() > 1 || groups.len() > 1))
+    {
+        return None;
+    }
+
+    let int_digits = groups.concat();
+    match frac_part {
+        None => format!("{}{}", sign, int_digits)
+            .parse::<i64>()
+            .ok()
+            .map(Data::Int),
+        Some(frac) if all_digits(frac) => format!("{}{}.{}", sign, int_digits, frac)
+            .parse::<f64>()
+            .ok()
+            .map(Data::Float),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(encoding: Option<&str>) -> SheetOptions {
+        SheetOptions {
+            encoding: encoding.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn cell(range: &Range<Data>, row: u32, col: u32) -> &Data {
+        range.get_value((row, col)).unwrap()
+    }
+
+    #[test]
+    fn parses_numbers_with_grouping() {
+        let cases = [
+            ("1.234,56", true, Some(Data::Float(1234.56))),
+            ("1,234.56", false, Some(Data::Float(1234.56))),
+            ("1.234.567", true, Some(Data::Int(1234567))),
+            ("-1.234", true, Some(Data::Int(-1234))),
+            ("1234", true, Some(Data::Int(1234))),
+            ("0", true, Some(Data::Int(0))),
+            ("0,5", true, Some(Data::Float(0.5))),
+            ("0.5", false, Some(Data::Float(0.5))),
+            // Gruplama üçlü değilse sayı sayılmaz
+            ("1.23", true, None),
+            ("1234.567", true, None),
+            ("1,5", false, None),
+            ("1,", true, None),
+            ("1.234,5a", true, None),
+            ("A1-1", true, None),
+        ];
+
+        for (input, decimal_comma, expected) in cases {
+            assert_eq!(
+                parse_number(input, decimal_comma),
+                expected,
+                "girdi: {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn keeps_leading_zero_numbers_as_text() {
+        for input in ["05551234567", "0555 123 45 67", "0.555", "00,5"] {
+            assert_eq!(parse_number(input, true), None, "girdi: {:?}", input);
+        }
+        assert_eq!(
+            csv_cell(" 05551234567 ", true),
+            Data::String("05551234567".into())
+        );
+        assert_eq!(csv_cell("  ", true), Data::Empty);
+    }
+
+    #[test]
+    fn detects_delimiter_from_first_line() {
+        assert_eq!(detect_delimiter("Daire No;Ad Soyad;GSM\nA1-1,x;y"), b';');
+        assert_eq!(detect_delimiter("Daire No,Ad Soyad,GSM"), b',');
+        assert_eq!(detect_delimiter("Daire No\tAd Soyad"), b'\t');
+        // Ayırıcı bulunamazsa ";" kabul edilir
+        assert_eq!(detect_delimiter("Daire No"), b';');
+        assert_eq!(detect_delimiter(""), b';');
+    }
+
+    #[test]
+    fn reads_semicolon_csv_with_decimal_comma() {
+        let data = "Daire No;Brüt m²;GSM\nA1-1;1.234,56;05551234567\nA1-2;;\n";
+        let range = read_csv(data.as_bytes(), &options(None)).unwrap();
+
+        assert_eq!(range.height(), 3);
+        assert_eq!(range.width(), 3);
+        assert_eq!(cell(&range, 0, 1), &Data::String("Brüt m²".into()));
+        assert_eq!(cell(&range, 1, 1), &Data::Float(1234.56));
+        assert_eq!(cell(&range, 1, 2), &Data::String("05551234567".into()));
+        assert_eq!(cell(&range, 2, 1), &Data::Empty);
+    }
+
+    #[test]
+    fn reads_comma_csv_with_decimal_point() {
+        let data = "Daire No,Brüt m²\nA1-1,\"1,234.5\"\n";
+        let range = read_csv(data.as_bytes(), &options(None)).unwrap();
+        assert_eq!(cell(&range, 1, 1), &Data::Float(1234.5));
+
+        let forced = SheetOptions {
+            delimiter: Some(";".into()),
+            ..Default::default()
+        };
+        let range = read_csv(data.as_bytes(), &forced).unwrap();
+        assert_eq!(range.width(), 1);
+    }
+
+    #[test]
+    fn decodes_windows_1254() {
+        let (data, _, _) = encoding_rs::WINDOWS_1254.encode("Ad Soyad;İlçe\nŞükrü Işık;Şişli\n");
+
+        let err = read_csv(&data, &options(None)).unwrap_err();
+        assert!(err.contains("UTF-8"), "{}", err);
+        assert!(err.contains("windows-1254"), "{}", err);
+
+        let range = read_csv(&data, &options(Some("windows-1254"))).unwrap();
+        assert_eq!(cell(&range, 0, 1), &Data::String("İlçe".into()));
+        assert_eq!(cell(&range, 1, 0), &Data::String("Şükrü Işık".into()));
+
+        let err = read_csv(&data, &options(Some("latin-9x"))).unwrap_err();
+        assert_eq!(err, "Bilinmeyen karakter kodlaması: latin-9x");
+    }
+
+    #[test]
+    fn bom_overrides_given_encoding() {
+        let data = "\u{feff}Ad Soyad;İlçe\n".as_bytes();
+        let range = read_csv(data, &options(Some("windows-1254"))).unwrap();
+        assert_eq!(cell(&range, 0, 0), &Data::String("Ad Soyad".into()));
+        assert_eq!(cell(&range, 0, 1), &Data::String("İlçe".into()));
+    }
+
+    #[test]
+    fn reads_empty_csv() {
+        let range = read_csv(b"", &options(None)).unwrap();
+        assert!(range.is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{
+        AgendaDay, Appointment, AppointmentFilter, AppointmentWithDetails, CreateAppointment,
+        UpdateAppointment,
+    },
+};
+
+const APPOINTMENTS_WITH_DETAILS: &str = "SELECT a.*, p.daire_no, c.ad_soyad, c.gsm,
+        u.name || ' ' || u.last_name AS danisan_ad_soyad,
+        (a.starts_at AT TIME ZONE 'Europe/Istanbul')::DATE AS agenda_date
+     FROM appointments a
+     JOIN properties p ON p.id = a.property_id
+     JOIN customers c ON c.id = a.customer_id
+     JOIN users u ON u.id = a.danisan_id";
+
+impl Database {
+    pub async fn list_appointments(
+        &self,
+        site_id: Uuid,
+        filter: &AppointmentFilter,
+    ) -> Result<Vec<AppointmentWithDetails>, sqlx::Error> {
+        let mut query = QueryBuilder::<Postgres>::new(APPOINTMENTS_WITH_DETAILS);
+        query.push(" WHERE p.site_id = ").push_bind(site_id);
+
+        if let Some(property_id) = filter.property_id {
+            query.push(" AND a.property_id = ").push_bind(property_id);
+        }
+        if let Some(customer_id) = filter.customer_id {
+            query.push(" AND a.customer_id = ").push_bind(customer_id);
+        }
+        if let Some(danisan_id) = filter.danisan_id {
+            query.push(" AND a.danisan_id = ").push_bind(danisan_id);
+        }
+        if let Some(status) = filter.status {
+            query.push(" AND a.status = ").push_bind(status);
+        }
+        if let Some(from) = filter.from {
+            query.push(" AND a.ends_at > ").push_bind(from);
+        }
+        if let Some(to) = filter.to {
+            query.push(" AND a.starts_at < ").push_bind(to);
+        }
+        query.push(" ORDER BY a.starts_at");
+
+        query
+            .build_query_as::<AppointmentWithDetails>()
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn consultant_agenda(
+        &self,
+        site_id: Uuid,
+        danisan_id: Uuid,
+        from: chrono::NaiveDate,
+        days: i32,
+    ) -> Result<Vec<AgendaDay>, sqlx::Error> {
+        let appointments = sqlx::query_as::<_, AppointmentWithDetails>(&format!(
+            "{APPOINTMENTS_WITH_DETAILS}
+         WHERE a.danisan_id = $1 AND p.site_id = $4
+           AND a.status <> 'cancelled'
+           AND a.starts_at >= ($2::DATE)::TIMESTAMP AT TIME ZONE 'Europe/Istanbul'
+           AND a.starts_at < ($2::DATE + $3)::TIMESTAMP AT TIME ZONE 'Europe/Istanbul'
+         ORDER BY a.starts_at"
+        ))
+        .bind(danisan_id)
+        .bind(from)
+        .bind(days)
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut agenda: Vec<AgendaDay> = (0..days)
+            .filter_map(|i| from.checked_add_days(chrono::Days::new(i as u64)))
+            .map(|date| AgendaDay {
+                date,
+                appointments: Vec::new(),
+            })
+            .collect();
+
+        for appointment in appointments {
+            if let Some(day) = agenda
+                .iter_mut()
+                .find(|d| d.date == appointment.agenda_date)
+            {
+                day.appointments.push(appointment);
+            }
+        }
+
+        Ok(agenda)
+    }
+
+    pub async fn create_appointment(
+        &self,
+        site_id: Uuid,
+        danisan_id: Uuid,
+        created_by: Uuid,
+        body: &CreateAppointment,
+    ) -> Result<Appointment, sqlx::Error> {
+        self.check_property_site(site_id, body.property_id).await?;
+        self.check_customers_site(site_id, &[body.customer_id])
+            .await?;
+        if let Some(lead_id) = body.lead_id {
+            self.check_lead_site(site_id, lead_id, body.customer_id)
+                .await?;
+        }
+
+        sqlx::query_as::<_, Appointment>(
+            "INSERT INTO appointments
+                (property_id, customer_id, danisan_id, lead_id, starts_at, ends_at, notes, created_by)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+         RETURNING *",
+        )
+        .bind(body.property_id)
+        .bind(body.customer_id)
+        .bind(danisan_id)
+        .bind(body.lead_id)
+        .bind(body.starts_at)
+        .bind(body.ends_at)
+        .bind(&body.notes)
+        .bind(created_by)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn update_appointment(
+        &self,
+        site_id: Uuid,
+        appointment_id: Uuid,
+        body: &UpdateAppointment,
+    ) -> Result<Appointment, sqlx::Error> {
+        sqlx::query_as::<_, Appointment>(
+            "UPDATE appointments SET
+                starts_at = COALESCE($1, starts_at),
+                ends_at = COALESCE($2, ends_at),
+                status = COALESCE($3, status),
+                notes = COALESCE($4, notes)
+         WHERE id = $5 AND property_id IN (SELECT id FROM properties WHERE site_id = $6)
+         RETURNING *",
+        )
+        .bind(body.starts_at)
+        .bind(body.ends_at)
+        .bind(body.status)
+        .bind(&body.notes)
+        .bind(appointment_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+}
@@ -1,12 +1,16 @@
 use crate::database::Database;
 use crate::models::{
     CreateCustomer, CreateCustomerNote, Customer, CustomerDetail, CustomerNote,
-    CustomerWithProperties, Property, Reminder,
+    CustomerWithProperties, OwnedProperty, OwnershipHistoryEntry, Reminder,
 };
+use sqlx::PgConnection;
 use uuid::Uuid;
 
 impl Database {
-    pub async fn list_customers(&self) -> Result<Vec<CustomerWithProperties>, sqlx::Error> {
+    pub async fn list_customers(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Vec<CustomerWithProperties>, sqlx::Error> {
         sqlx::query_as::<_, CustomerWithProperties>(
             "SELECT 
             c.id, c.ad_soyad, c.gsm, c.telefon, c.email, c.acil_kisi, 
@@ -14,20 +18,28 @@ impl Database {
             COUNT(p.id) AS property_count,
             STRING_AGG(p.daire_no, ', ' ORDER BY p.daire_no) AS daire_nolar
          FROM customers c
-         LEFT JOIN properties p ON p.sahip_id = c.id
+         LEFT JOIN current_property_owners po ON po.customer_id = c.id
+         LEFT JOIN properties p ON p.id = po.property_id
+         WHERE c.site_id = $1
          GROUP BY c.id
          ORDER BY c.en_son_gorusuldu DESC",
         )
+        .bind(site_id)
         .fetch_all(&self.pool)
         .await
     }
 
-    pub async fn customer_detail(&self, customer_id: Uuid) -> Result<CustomerDetail, sqlx::Error> {
+    pub async fn customer_detail(
+        &self,
+        site_id: Uuid,
+        customer_id: Uuid,
+    ) -> Result<CustomerDetail, sqlx::Error> {
         let customer: Customer = sqlx::query_as::<_, Customer>(
         "SELECT id, ad_soyad, gsm, telefon, email, acil_kisi, uyruk, en_son_gorusuldu, danisan_id 
-         FROM customers WHERE id = $1"
+         FROM customers WHERE id = $1 AND site_id = $2"
     )
     .bind(customer_id)
+    .bind(site_id)
     .fetch_optional(&self.pool)
     .await?
     .ok_or(sqlx::Error::RowNotFound)?;
@@ -40,13 +52,30 @@ impl Database {
         .fetch_all(&self.pool)
         .await?;
 
-        let properties: Vec<Property> = sqlx::query_as::<_, Property>(
-            "SELECT * FROM properties WHERE sahip_id = $1 ORDER BY daire_no",
+        let properties: Vec<OwnedProperty> = sqlx::query_as::<_, OwnedProperty>(
+            "SELECT p.*, po.share_percent, po.start_date
+         FROM properties p
+         JOIN current_property_owners po ON po.property_id = p.id
+         WHERE po.customer_id = $1
+         ORDER BY p.daire_no",
         )
         .bind(customer_id)
         .fetch_all(&self.pool)
         .await?;
 
+        let ownership_history: Vec<OwnershipHistoryEntry> =
+            sqlx::query_as::<_, OwnershipHistoryEntry>(
+                "SELECT po.property_id, p.daire_no, po.share_percent, po.start_date,
+                    po.end_date, po.transfer_in_id, po.transfer_out_id
+             FROM property_owners po
+             JOIN properties p ON p.id = po.property_id
+             WHERE po.customer_id = $1
+             ORDER BY po.start_date DESC NULLS LAST, p.daire_no",
+            )
+            .bind(customer_id)
+            .fetch_all(&self.pool)
+            .await?;
+
         let reminders: Vec<Reminder> = sqlx::query_as::<_, Reminder>(
             "SELECT * FROM reminders WHERE customer_id = $1 ORDER BY reminder_date",
         )
@@ -54,42 +83,39 @@ impl Database {
         .fetch_all(&self.pool)
         .await?;
 
+        let tickets = self.list_customer_tickets(site_id, customer_id).await?;
+
         Ok(CustomerDetail {
             customer_info: customer,
             customer_notes: notes,
             customer_properties: properties,
+            customer_ownership_history: ownership_history,
             customer_reminders: reminders,
+            customer_tickets: tickets,
         })
     }
 
-    pub async fn create_customer(&self, body: &CreateCustomer) -> Result<Customer, sqlx::Error> {
-        sqlx::query_as::<_, Customer>(
-        "INSERT INTO customers (ad_soyad, gsm, telefon, email, acil_kisi, uyruk, en_son_gorusuldu, danisan_id)
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-         RETURNING *"
-        )
-        .bind(&body.ad_soyad)
-        .bind(&body.gsm)
-        .bind(&body.telefon)
-        .bind(&body.email)
-        .bind(&body.acil_kisi)
-        .bind(&body.uyruk)
-        .bind(body.en_son_gorusuldu)
-        .bind(body.danisan_id)
-        .fetch_one(&self.pool)
-        .await
+    pub async fn create_customer(
+        &self,
+        site_id: Uuid,
+        body: &CreateCustomer,
+    ) -> Result<Customer, sqlx::Error> {
+        let mut conn = self.pool.acquire().await?;
+        insert_customer(&mut conn, site_id, body).await
     }
 
     pub async fn assign_consultant(
         &self,
+        site_id: Uuid,
         customer_id: Uuid,
         danisan_id: Uuid,
     ) -> Result<Customer, sqlx::Error> {
         sqlx::query_as::<_, Customer>(
-            "UPDATE customers SET danisan_id = $1 WHERE id = $2 RETURNING *",
+            "UPDATE customers SET danisan_id = $1 WHERE id = $2 AND site_id = $3 RETURNING *",
         )
         .bind(danisan_id)
         .bind(customer_id)
+        .bind(site_id)
         .fetch_optional(&self.pool)
         .await?
         .ok_or(sqlx::Error::RowNotFound)
@@ -97,27 +123,41 @@ impl Database {
 
     pub async fn create_customer_note(
         &self,
+        site_id: Uuid,
         customer_id: Uuid,
         user_id: Uuid,
         body: &CreateCustomerNote,
     ) -> Result<CustomerNote, sqlx::Error> {
         sqlx::query_as::<_, CustomerNote>(
             "INSERT INTO customer_notes (note, created_by, customer_id)
-         VALUES ($1, $2, $3)
+         SELECT $1, $2, id FROM customers WHERE id = $3 AND site_id = $4
          RETURNING *",
         )
         .bind(&body.note)
         .bind(user_id)
         .bind(customer_id)
-        .fetch_one(&self.pool)
-        .await
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
     }
 
-    pub async fn delete_customer_note(&self, note_id: Uuid) -> Result<(), sqlx::Error> {
-        let result = sqlx::query("DELETE FROM customer_notes WHERE id = $1")
-            .bind(note_id)
-            .execute(&self.pool)
-            .await?;
+    pub async fn delete_customer_note(
+        &self,
+        site_id: Uuid,
+        customer_id: Uuid,
+        note_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        let result = sqlx::query(
+            "DELETE FROM customer_notes n
+             USING customers c
+             WHERE n.id = $1 AND n.customer_id = c.id AND c.id = $2 AND c.site_id = $3",
+        )
+        .bind(note_id)
+        .bind(customer_id)
+        .bind(site_id)
+        .execute(&self.pool)
+        .await?;
 
         if result.rows_affected() == 0 {
             return Err(sqlx::Error::RowNotFound);
@@ -126,57 +166,43 @@ impl Database {
         Ok(())
     }
 
-    pub async fn get_or_create_customer(
+    pub async fn update_last_contact(
         &self,
-        ad_soyad: &str,
-        gsm: &str,
-        telefon: &str,
-        email: &str,
-        acil_kisi: &str,
-        uyruk: &str,
+        site_id: Uuid,
+        customer_id: Uuid,
     ) -> Result<Customer, sqlx::Error> {
-        let existing = sqlx::query_as::<_, Customer>(
-            "SELECT * FROM customers WHERE ad_soyad = $1 AND gsm = $2",
-        )
-        .bind(ad_soyad)
-        .bind(if gsm.is_empty() { None } else { Some(gsm) })
-        .fetch_optional(&self.pool)
-        .await?;
-
-        if let Some(customer) = existing {
-            return Ok(customer);
-        }
-
-        sqlx::query_as::<_, Customer>(
-            "INSERT INTO customers (ad_soyad, gsm, telefon, email, acil_kisi, uyruk)
-         VALUES ($1, $2, $3, $4, $5, $6)
-         RETURNING *",
-        )
-        .bind(ad_soyad)
-        .bind(if gsm.is_empty() { None } else { Some(gsm) })
-        .bind(if telefon.is_empty() {
-            None
-        } else {
-            Some(telefon)
-        })
-        .bind(if email.is_empty() { None } else { Some(email) })
-        .bind(if acil_kisi.is_empty() {
-            None
-        } else {
-            Some(acil_kisi)
-        })
-        .bind(if uyruk.is_empty() { None } else { Some(uyruk) })
-        .fetch_one(&self.pool)
-        .await
-    }
-
-    pub async fn update_last_contact(&self, customer_id: Uuid) -> Result<Customer, sqlx::Error> {
         sqlx::query_as::<_, Customer>(
-            "UPDATE customers SET en_son_gorusuldu = NOW() WHERE id = $1 RETURNING *",
+            "UPDATE customers SET en_son_gorusuldu = NOW()
+             WHERE id = $1 AND site_id = $2
+             RETURNING *",
         )
         .bind(customer_id)
+        .bind(site_id)
         .fetch_optional(&self.pool)
         .await?
         .ok_or(sqlx::Error::RowNotFound)
     }
 }
+
+pub(super) async fn insert_customer(
+    conn: &mut PgConnection,
+    site_id: Uuid,
+    body: &CreateCustomer,
+) -> Result<Customer, sqlx::Error> {
+    sqlx::query_as::<_, Customer>(
+        "INSERT INTO customers (ad_soyad, gsm, telefon, email, acil_kisi, uyruk, en_son_gorusuldu, danisan_id, site_id)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         RETURNING *",
+    )
+    .bind(&body.ad_soyad)
+    .bind(&body.gsm)
+    .bind(&body.telefon)
+    .bind(&body.email)
+    .bind(&body.acil_kisi)
+    .bind(&body.uyruk)
+    .bind(body.en_son_gorusuldu)
+    .bind(body.danisan_id)
+    .bind(site_id)
+    .fetch_one(conn)
+    .await
+}
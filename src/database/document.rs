@@ -0,0 +1,109 @@
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{Document, DocumentOwner, NewDocument},
+};
+
+// Belgenin sahibi (müşteri, daire ya da kira sözleşmesi) $2 sitesine ait olmalı
+const DOCUMENT_IN_SITE: &str = "(customer_id IN (SELECT id FROM customers WHERE site_id = $2)
+     OR property_id IN (SELECT id FROM properties WHERE site_id = $2)
+     OR lease_id IN (
+         SELECT l.id FROM leases l JOIN properties p ON p.id = l.property_id WHERE p.site_id = $2
+     ))";
+
+impl Database {
+    pub async fn list_documents(
+        &self,
+        site_id: Uuid,
+        owner: DocumentOwner,
+    ) -> Result<Vec<Document>, sqlx::Error> {
+        let (column, id) = owner_column(owner);
+
+        sqlx::query_as::<_, Document>(&format!(
+            "SELECT * FROM documents WHERE {} = $1 AND {} ORDER BY created_at DESC",
+            column, DOCUMENT_IN_SITE
+        ))
+        .bind(id)
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn get_document(
+        &self,
+        site_id: Uuid,
+        document_id: Uuid,
+    ) -> Result<Document, sqlx::Error> {
+        sqlx::query_as::<_, Document>(&format!(
+            "SELECT * FROM documents WHERE id = $1 AND {}",
+            DOCUMENT_IN_SITE
+        ))
+        .bind(document_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn create_document(
+        &self,
+        site_id: Uuid,
+        doc: &NewDocument,
+    ) -> Result<Document, sqlx::Error> {
+        match doc.owner {
+            DocumentOwner::Customer(id) => self.check_customers_site(site_id, &[id]).await?,
+            DocumentOwner::Property(id) => self.check_property_site(site_id, id).await?,
+            DocumentOwner::Lease(id) => self.check_lease_site(site_id, id).await?,
+        }
+
+        let (column, id) = owner_column(doc.owner);
+
+        sqlx::query_as::<_, Document>(&format!(
+            "INSERT INTO documents
+                ({}, category, file_name, content_type, size_bytes, checksum, storage_key, uploaded_by)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+         RETURNING *",
+            column
+        ))
+        .bind(id)
+        .bind(doc.category)
+        .bind(&doc.file_name)
+        .bind(&doc.content_type)
+        .bind(doc.size_bytes)
+        .bind(&doc.checksum)
+        .bind(&doc.storage_key)
+        .bind(doc.uploaded_by)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn delete_document(
+        &self,
+        site_id: Uuid,
+        document_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        let result = sqlx::query(&format!(
+            "DELETE FROM documents WHERE id = $1 AND {}",
+            DOCUMENT_IN_SITE
+        ))
+        .bind(document_id)
+        .bind(site_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+}
+
+fn owner_column(owner: DocumentOwner) -> (&'static str, Uuid) {
+    match owner {
+        DocumentOwner::Customer(id) => ("customer_id", id),
+        DocumentOwner::Property(id) => ("property_id", id),
+        DocumentOwner::Lease(id) => ("lease_id", id),
+    }
+}
@@ -0,0 +1,59 @@
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::database::{Database, property::push_property_filters};
+use crate::models::{CustomerExportRow, PropertyExportRow, PropertyFilter};
+
+impl Database {
+    pub async fn export_customers(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Vec<CustomerExportRow>, sqlx::Error> {
+        sqlx::query_as::<_, CustomerExportRow>(
+            "SELECT
+            c.ad_soyad, c.gsm, c.telefon, c.email, c.acil_kisi, c.uyruk,
+            u.name || ' ' || u.last_name AS danisan,
+            c.en_son_gorusuldu,
+            COUNT(p.id) AS property_count,
+            STRING_AGG(p.daire_no, ', ' ORDER BY p.daire_no) AS daire_nolar
+         FROM customers c
+         LEFT JOIN users u ON u.id = c.danisan_id
+         LEFT JOIN current_property_owners po ON po.customer_id = c.id
+         LEFT JOIN properties p ON p.id = po.property_id
+         WHERE c.site_id = $1
+         GROUP BY c.id, u.id
+         ORDER BY c.ad_soyad",
+        )
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    // Liste ucu ile aynı filtreler uygulanır, sayfalama yapılmaz
+    pub async fn export_properties(
+        &self,
+        site_id: Uuid,
+        filter: &PropertyFilter,
+    ) -> Result<Vec<PropertyExportRow>, sqlx::Error> {
+        let mut query = QueryBuilder::<Postgres>::new(
+            "SELECT
+            p.daire_no, p.blok, p.kat, p.kapi_no, p.daire_tipi, p.oda_sayisi,
+            p.brut_m2, p.net_m2, p.balkon_m2, p.cephe::TEXT AS cephe, p.kiraci_var_mi,
+            COUNT(c.id) AS sahip_sayisi,
+            STRING_AGG(c.ad_soyad, ', ' ORDER BY c.ad_soyad) AS sahipler,
+            STRING_AGG(DISTINCT u.name || ' ' || u.last_name, ', ') AS danismanlar,
+            MAX(c.en_son_gorusuldu) AS en_son_gorusuldu
+         FROM properties p
+         LEFT JOIN current_property_owners po ON po.property_id = p.id
+         LEFT JOIN customers c ON c.id = po.customer_id
+         LEFT JOIN users u ON u.id = c.danisan_id",
+        );
+        push_property_filters(&mut query, site_id, filter);
+        query.push(" GROUP BY p.id ORDER BY p.daire_no");
+
+        query
+            .build_query_as::<PropertyExportRow>()
+            .fetch_all(&self.pool)
+            .await
+    }
+}
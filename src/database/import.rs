@@ -0,0 +1,451 @@
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::{
+    database::{
+        Database, customer::insert_customer, ownership::replace_property_owners,
+        tenant::insert_tenant,
+    },
+    models::{
+        CreateCustomer, CreateProperty, CreateTenant, Customer, DaireNo, ImportRun, ImportRunError,
+        MissingRecord, NewImportRun, NewPropertyOwner, Property, Tenant, UnmatchedTenant,
+    },
+};
+
+// İçe aktarma komutlarının tüm okuma ve yazmaları tek bir işlem içinde yapılır;
+// böylece aynı dosyada daha önce oluşturulan kayıtlar sonraki satırlarda görülür.
+pub struct ImportSession {
+    tx: Transaction<'static, Postgres>,
+}
+
+impl Database {
+    pub async fn begin_import(&self) -> Result<ImportSession, sqlx::Error> {
+        Ok(ImportSession {
+            tx: self.pool.begin().await?,
+        })
+    }
+
+    pub async fn get_import_run(&self, site_id: Uuid, id: Uuid) -> Result<ImportRun, sqlx::Error> {
+        sqlx::query_as::<_, ImportRun>("SELECT * FROM import_runs WHERE id = $1 AND site_id = $2")
+            .bind(id)
+            .bind(site_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn list_import_run_unmatched_tenants(
+        &self,
+        run_id: Uuid,
+    ) -> Result<Vec<UnmatchedTenant>, sqlx::Error> {
+        sqlx::query_as::<_, UnmatchedTenant>(
+            "SELECT row_number, daire_no, ad_soyad, reason FROM import_run_unmatched_tenants
+             WHERE run_id = $1
+             ORDER BY row_number",
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn list_import_run_missing_records(
+        &self,
+        run_id: Uuid,
+    ) -> Result<Vec<MissingRecord>, sqlx::Error> {
+        sqlx::query_as::<_, MissingRecord>(
+            "SELECT row_key, message FROM import_run_missing_records
+             WHERE run_id = $1
+             ORDER BY row_key",
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn list_import_run_errors(
+        &self,
+        run_id: Uuid,
+    ) -> Result<Vec<ImportRunError>, sqlx::Error> {
+        sqlx::query_as::<_, ImportRunError>(
+            "SELECT row_number, row_key, message FROM import_run_errors
+             WHERE run_id = $1
+             ORDER BY row_number",
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+}
+
+impl ImportSession {
+    pub async fn commit(self) -> Result<(), sqlx::Error> {
+        self.tx.commit().await
+    }
+
+    pub async fn rollback(self) -> Result<(), sqlx::Error> {
+        self.tx.rollback().await
+    }
+
+    pub async fn savepoint(&mut self, name: &'static str) -> Result<(), sqlx::Error> {
+        sqlx::query(&format!("SAVEPOINT {}", name))
+            .execute(&mut *self.tx)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn release_savepoint(&mut self, name: &'static str) -> Result<(), sqlx::Error> {
+        sqlx::query(&format!("RELEASE SAVEPOINT {}", name))
+            .execute(&mut *self.tx)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn rollback_to_savepoint(&mut self, name: &'static str) -> Result<(), sqlx::Error> {
+        sqlx::query(&format!("ROLLBACK TO SAVEPOINT {}", name))
+            .execute(&mut *self.tx)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn ensure_site(&mut self, name: &str) -> Result<Uuid, sqlx::Error> {
+        sqlx::query_scalar(
+            "INSERT INTO sites (name) VALUES ($1)
+             ON CONFLICT (name) DO UPDATE SET name = EXCLUDED.name
+             RETURNING id",
+        )
+        .bind(name)
+        .fetch_one(&mut *self.tx)
+        .await
+    }
+
+    // Blok yeni oluşturulduysa ikinci değer true döner
+    pub async fn ensure_block(
+        &mut self,
+        site_id: Uuid,
+        name: &str,
+    ) -> Result<(Uuid, bool), sqlx::Error> {
+        sqlx::query_as(
+            "INSERT INTO blocks (site_id, name) VALUES ($1, $2)
+             ON CONFLICT (site_id, name) DO UPDATE SET name = EXCLUDED.name
+             RETURNING id, (xmax = 0)",
+        )
+        .bind(site_id)
+        .bind(name)
+        .fetch_one(&mut *self.tx)
+        .await
+    }
+
+    pub async fn get_property_by_daire_no(
+        &mut self,
+        site_id: Uuid,
+        daire_no: &DaireNo,
+    ) -> Result<Option<Property>, sqlx::Error> {
+        sqlx::query_as::<_, Property>(
+            "SELECT * FROM properties WHERE site_id = $1 AND daire_no = $2",
+        )
+        .bind(site_id)
+        .bind(daire_no.to_string())
+        .fetch_optional(&mut *self.tx)
+        .await
+    }
+
+    // Yeni kayıt eklendiyse true döner
+    pub async fn upsert_property(&mut self, prop: &CreateProperty) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar(
+        "INSERT INTO properties (daire_no, block_id, kat, kapi_no, oda_sayisi, daire_tipi, brut_m2, net_m2, balkon_m2, cephe)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+         ON CONFLICT (site_id, daire_no) DO UPDATE SET
+             block_id = EXCLUDED.block_id,
+             kat = EXCLUDED.kat,
+             kapi_no = EXCLUDED.kapi_no,
+             oda_sayisi = EXCLUDED.oda_sayisi,
+             daire_tipi = EXCLUDED.daire_tipi,
+             brut_m2 = EXCLUDED.brut_m2,
+             net_m2 = EXCLUDED.net_m2,
+             balkon_m2 = EXCLUDED.balkon_m2,
+             cephe = EXCLUDED.cephe
+         RETURNING (xmax = 0)"
+        )
+        .bind(&prop.daire_no)
+        .bind(prop.block_id)
+        .bind(&prop.kat)
+        .bind(prop.kapi_no)
+        .bind(&prop.oda_sayisi)
+        .bind(&prop.daire_tipi)
+        .bind(&prop.brut_m2)
+        .bind(&prop.net_m2)
+        .bind(&prop.balkon_m2)
+        .bind(prop.cephe)
+        .fetch_one(&mut *self.tx)
+        .await
+    }
+
+    // GSM boş olan müşteriler de ad soyadla eşleşir; aksi halde her çalıştırmada yeniden oluşturulur
+    pub async fn find_customer(
+        &mut self,
+        site_id: Uuid,
+        body: &CreateCustomer,
+    ) -> Result<Option<Customer>, sqlx::Error> {
+        sqlx::query_as::<_, Customer>(
+            "SELECT * FROM customers
+             WHERE ad_soyad = $1 AND gsm IS NOT DISTINCT FROM $2 AND site_id = $3
+             LIMIT 1",
+        )
+        .bind(&body.ad_soyad)
+        .bind(&body.gsm)
+        .bind(site_id)
+        .fetch_optional(&mut *self.tx)
+        .await
+    }
+
+    // Dosyada boş bırakılan iletişim bilgileri silinmez
+    pub async fn update_customer_contacts(
+        &mut self,
+        customer_id: Uuid,
+        body: &CreateCustomer,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE customers SET
+                telefon = COALESCE($2, telefon),
+                email = COALESCE($3, email),
+                acil_kisi = COALESCE($4, acil_kisi),
+                uyruk = COALESCE($5, uyruk)
+             WHERE id = $1",
+        )
+        .bind(customer_id)
+        .bind(&body.telefon)
+        .bind(&body.email)
+        .bind(&body.acil_kisi)
+        .bind(&body.uyruk)
+        .execute(&mut *self.tx)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn create_customer(
+        &mut self,
+        site_id: Uuid,
+        body: &CreateCustomer,
+    ) -> Result<Customer, sqlx::Error> {
+        insert_customer(&mut self.tx, site_id, body).await
+    }
+
+    pub async fn find_tenant(
+        &mut self,
+        site_id: Uuid,
+        body: &CreateTenant,
+    ) -> Result<Option<Tenant>, sqlx::Error> {
+        sqlx::query_as::<_, Tenant>(
+            "SELECT * FROM tenants
+             WHERE ad_soyad = $1 AND gsm IS NOT DISTINCT FROM $2 AND site_id = $3
+             ORDER BY created_at
+             LIMIT 1",
+        )
+        .bind(&body.ad_soyad)
+        .bind(&body.gsm)
+        .bind(site_id)
+        .fetch_optional(&mut *self.tx)
+        .await
+    }
+
+    pub async fn create_tenant(
+        &mut self,
+        site_id: Uuid,
+        body: &CreateTenant,
+    ) -> Result<Tenant, sqlx::Error> {
+        insert_tenant(&mut self.tx, site_id, body).await
+    }
+
+    // Dosyada boş bırakılan iletişim bilgileri silinmez
+    pub async fn update_tenant_contacts(
+        &mut self,
+        tenant_id: Uuid,
+        body: &CreateTenant,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE tenants SET
+                gsm = COALESCE($2, gsm),
+                telefon = COALESCE($3, telefon),
+                email = COALESCE($4, email),
+                acil_kisi = COALESCE($5, acil_kisi),
+                uyruk = COALESCE($6, uyruk)
+             WHERE id = $1",
+        )
+        .bind(tenant_id)
+        .bind(&body.gsm)
+        .bind(&body.telefon)
+        .bind(&body.email)
+        .bind(&body.acil_kisi)
+        .bind(&body.uyruk)
+        .execute(&mut *self.tx)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn active_lease_tenant(
+        &mut self,
+        property_id: Uuid,
+    ) -> Result<Option<Tenant>, sqlx::Error> {
+        sqlx::query_as::<_, Tenant>(
+            "SELECT t.* FROM leases l
+             JOIN tenants t ON t.id = l.tenant_id
+             WHERE l.property_id = $1 AND l.status = 'active'",
+        )
+        .bind(property_id)
+        .fetch_optional(&mut *self.tx)
+        .await
+    }
+
+    // kiraci_var_mi, leases tablosundaki tetikleyiciyle güncellenir
+    pub async fn create_active_lease(
+        &mut self,
+        property_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO leases (property_id, tenant_id, start_date, status)
+             VALUES ($1, $2, CURRENT_DATE, 'active')",
+        )
+        .bind(property_id)
+        .bind(tenant_id)
+        .execute(&mut *self.tx)
+        .await?;
+        Ok(())
+    }
+
+    // Güncel sahiplerin müşteri kimliği ve adı
+    pub async fn current_owners(
+        &mut self,
+        property_id: Uuid,
+    ) -> Result<Vec<(Uuid, String)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT c.id, c.ad_soyad FROM current_property_owners po
+             JOIN customers c ON c.id = po.customer_id
+             WHERE po.property_id = $1
+             ORDER BY c.ad_soyad",
+        )
+        .bind(property_id)
+        .fetch_all(&mut *self.tx)
+        .await
+    }
+
+    // Sitedeki daireler: kimlik, daire no ve güncel sahiplerin adları
+    pub async fn list_site_properties(
+        &mut self,
+        site_id: Uuid,
+    ) -> Result<Vec<(Uuid, String, Option<String>)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT p.id, p.daire_no, STRING_AGG(c.ad_soyad, ', ' ORDER BY c.ad_soyad)
+             FROM properties p
+             LEFT JOIN current_property_owners po ON po.property_id = p.id
+             LEFT JOIN customers c ON c.id = po.customer_id
+             WHERE p.site_id = $1
+             GROUP BY p.id
+             ORDER BY p.daire_no",
+        )
+        .bind(site_id)
+        .fetch_all(&mut *self.tx)
+        .await
+    }
+
+    pub async fn set_property_owners(
+        &mut self,
+        property_id: Uuid,
+        owners: &[NewPropertyOwner],
+    ) -> Result<(), sqlx::Error> {
+        replace_property_owners(&mut self.tx, property_id, owners).await
+    }
+
+    pub async fn find_committed_run(
+        &mut self,
+        site_id: Uuid,
+        kind: &str,
+        file_hash: &str,
+    ) -> Result<Option<ImportRun>, sqlx::Error> {
+        sqlx::query_as::<_, ImportRun>(
+            "SELECT * FROM import_runs
+             WHERE site_id = $1 AND kind = $2 AND file_hash = $3 AND status = 'committed'
+             ORDER BY finished_at DESC
+             LIMIT 1",
+        )
+        .bind(site_id)
+        .bind(kind)
+        .bind(file_hash)
+        .fetch_optional(&mut *self.tx)
+        .await
+    }
+
+    pub async fn create_import_run(
+        &mut self,
+        run: &NewImportRun,
+        errors: &[ImportRunError],
+        unmatched_tenants: &[UnmatchedTenant],
+        missing: &[MissingRecord],
+    ) -> Result<ImportRun, sqlx::Error> {
+        let created = sqlx::query_as::<_, ImportRun>(
+            "INSERT INTO import_runs (id, site_id, kind, file_name, file_hash, mode, status,
+                created_count, updated_count, skipped_count, error_count, sync, missing_count,
+                user_id, started_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+             RETURNING *",
+        )
+        .bind(run.id)
+        .bind(run.site_id)
+        .bind(run.kind)
+        .bind(&run.file_name)
+        .bind(&run.file_hash)
+        .bind(run.mode)
+        .bind(run.status)
+        .bind(run.created_count)
+        .bind(run.updated_count)
+        .bind(run.skipped_count)
+        .bind(run.error_count)
+        .bind(run.sync)
+        .bind(run.missing_count)
+        .bind(run.user_id)
+        .bind(run.started_at)
+        .fetch_one(&mut *self.tx)
+        .await?;
+
+        for error in errors {
+            sqlx::query(
+                "INSERT INTO import_run_errors (run_id, row_number, row_key, message)
+                 VALUES ($1, $2, $3, $4)",
+            )
+            .bind(created.id)
+            .bind(error.row_number)
+            .bind(&error.row_key)
+            .bind(&error.message)
+            .execute(&mut *self.tx)
+            .await?;
+        }
+
+        for tenant in unmatched_tenants {
+            sqlx::query(
+                "INSERT INTO import_run_unmatched_tenants (run_id, row_number, daire_no, ad_soyad, reason)
+                 VALUES ($1, $2, $3, $4, $5)",
+            )
+            .bind(created.id)
+            .bind(tenant.row_number)
+            .bind(&tenant.daire_no)
+            .bind(&tenant.ad_soyad)
+            .bind(&tenant.reason)
+            .execute(&mut *self.tx)
+            .await?;
+        }
+
+        for record in missing {
+            sqlx::query(
+                "INSERT INTO import_run_missing_records (run_id, row_key, message)
+                 VALUES ($1, $2, $3)",
+            )
+            .bind(created.id)
+            .bind(&record.row_key)
+            .bind(&record.message)
+            .execute(&mut *self.tx)
+            .await?;
+        }
+
+        Ok(created)
+    }
+}
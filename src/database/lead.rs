@@ -0,0 +1,336 @@
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{
+        CreateLead, CreateLeadStage, Lead, LeadFilter, LeadStage, LeadWithDetails, MoveLeadStage,
+        Page, Pagination, PipelineColumn, UpdateLead,
+    },
+};
+
+const LEADS_WITH_DETAILS: &str = "SELECT l.*, c.ad_soyad, c.gsm,
+        s.name AS stage_name,
+        u.name || ' ' || u.last_name AS danisan_ad_soyad
+     FROM leads l
+     JOIN customers c ON c.id = l.customer_id
+     JOIN lead_stages s ON s.id = l.stage_id
+     LEFT JOIN users u ON u.id = l.danisan_id";
+
+impl Database {
+    pub async fn list_lead_stages(&self) -> Result<Vec<LeadStage>, sqlx::Error> {
+        sqlx::query_as::<_, LeadStage>("SELECT * FROM lead_stages ORDER BY position, name")
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn create_lead_stage(
+        &self,
+        body: &CreateLeadStage,
+    ) -> Result<LeadStage, sqlx::Error> {
+        sqlx::query_as::<_, LeadStage>(
+            "INSERT INTO lead_stages (name, position, is_won, is_lost)
+         VALUES ($1, $2, $3, $4)
+         RETURNING *",
+        )
+        .bind(&body.name)
+        .bind(body.position)
+        .bind(body.is_won)
+        .bind(body.is_lost)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn update_lead_stage(
+        &self,
+        stage_id: Uuid,
+        body: &CreateLeadStage,
+    ) -> Result<LeadStage, sqlx::Error> {
+        sqlx::query_as::<_, LeadStage>(
+            "UPDATE lead_stages SET name = $1, position = $2, is_won = $3, is_lost = $4
+         WHERE id = $5
+         RETURNING *",
+        )
+        .bind(&body.name)
+        .bind(body.position)
+        .bind(body.is_won)
+        .bind(body.is_lost)
+        .bind(stage_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn delete_lead_stage(&self, stage_id: Uuid) -> Result<(), sqlx::Error> {
+        let result = sqlx::query("DELETE FROM lead_stages WHERE id = $1")
+            .bind(stage_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    pub async fn list_leads(
+        &self,
+        site_id: Uuid,
+        filter: &LeadFilter,
+        pagination: &Pagination,
+    ) -> Result<Page<LeadWithDetails>, sqlx::Error> {
+        let mut count_query = QueryBuilder::<Postgres>::new(
+            "SELECT COUNT(*) FROM leads l
+             JOIN customers c ON c.id = l.customer_id
+             JOIN lead_stages s ON s.id = l.stage_id",
+        );
+        push_lead_filters(&mut count_query, site_id, filter);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut query = QueryBuilder::<Postgres>::new(LEADS_WITH_DETAILS);
+        push_lead_filters(&mut query, site_id, filter);
+        query
+            .push(" ORDER BY l.stage_changed_at DESC LIMIT ")
+            .push_bind(pagination.per_page())
+            .push(" OFFSET ")
+            .push_bind(pagination.offset());
+
+        let items = query
+            .build_query_as::<LeadWithDetails>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(Page {
+            items,
+            total,
+            page: pagination.page(),
+            per_page: pagination.per_page(),
+        })
+    }
+
+    pub async fn lead_pipeline(
+        &self,
+        site_id: Uuid,
+        filter: &LeadFilter,
+    ) -> Result<Vec<PipelineColumn>, sqlx::Error> {
+        let stages = self.list_lead_stages().await?;
+
+        let mut query = QueryBuilder::<Postgres>::new(LEADS_WITH_DETAILS);
+        push_lead_filters(&mut query, site_id, filter);
+        query.push(" ORDER BY l.stage_changed_at DESC");
+        let mut leads = query
+            .build_query_as::<LeadWithDetails>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(stages
+            .into_iter()
+            .map(|stage| {
+                let (in_stage, rest) = leads
+                    .drain(..)
+                    .partition(|l: &LeadWithDetails| l.lead.stage_id == stage.id);
+                leads = rest;
+                PipelineColumn {
+                    stage,
+                    leads: in_stage,
+                }
+            })
+            .collect())
+    }
+
+    pub async fn list_open_leads(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Vec<LeadWithDetails>, sqlx::Error> {
+        sqlx::query_as::<_, LeadWithDetails>(&format!(
+            "{LEADS_WITH_DETAILS}
+         WHERE c.site_id = $1 AND NOT (s.is_won OR s.is_lost)"
+        ))
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn get_open_lead(
+        &self,
+        site_id: Uuid,
+        lead_id: Uuid,
+    ) -> Result<Option<LeadWithDetails>, sqlx::Error> {
+        sqlx::query_as::<_, LeadWithDetails>(&format!(
+            "{LEADS_WITH_DETAILS}
+         WHERE l.id = $1 AND c.site_id = $2 AND NOT (s.is_won OR s.is_lost)"
+        ))
+        .bind(lead_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn get_lead(
+        &self,
+        site_id: Uuid,
+        lead_id: Uuid,
+    ) -> Result<LeadWithDetails, sqlx::Error> {
+        sqlx::query_as::<_, LeadWithDetails>(&format!(
+            "{LEADS_WITH_DETAILS}
+         WHERE l.id = $1 AND c.site_id = $2"
+        ))
+        .bind(lead_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn create_lead(
+        &self,
+        site_id: Uuid,
+        created_by: Uuid,
+        body: &CreateLead,
+    ) -> Result<Lead, sqlx::Error> {
+        if let Some(customer_id) = body.customer_id {
+            self.check_customers_site(site_id, &[customer_id]).await?;
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        let customer_id = match (body.customer_id, &body.prospect) {
+            (Some(customer_id), _) => customer_id,
+            (None, Some(prospect)) => {
+                sqlx::query_scalar(
+                    "INSERT INTO customers (ad_soyad, gsm, telefon, email, acil_kisi, uyruk, danisan_id, site_id)
+                 VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, $8), $9)
+                 RETURNING id",
+                )
+                .bind(&prospect.ad_soyad)
+                .bind(&prospect.gsm)
+                .bind(&prospect.telefon)
+                .bind(&prospect.email)
+                .bind(&prospect.acil_kisi)
+                .bind(&prospect.uyruk)
+                .bind(prospect.danisan_id)
+                .bind(body.danisan_id)
+                .bind(site_id)
+                .fetch_one(&mut *tx)
+                .await?
+            }
+            (None, None) => return Err(sqlx::Error::RowNotFound),
+        };
+
+        let lead = sqlx::query_as::<_, Lead>(
+            "INSERT INTO leads
+                (customer_id, stage_id, danisan_id, listing_type, oda_sayisi, blok, cephe,
+                 min_net_m2, min_budget, max_budget, currency, notes, created_by)
+         VALUES (
+             $1,
+             COALESCE($2, (SELECT id FROM lead_stages ORDER BY position LIMIT 1)),
+             $3, $4, $5, $6, $7, $8, $9, $10, COALESCE($11, 'TRY'), $12, $13
+         )
+         RETURNING *",
+        )
+        .bind(customer_id)
+        .bind(body.stage_id)
+        .bind(body.danisan_id)
+        .bind(body.listing_type)
+        .bind(&body.oda_sayisi)
+        .bind(&body.blok)
+        .bind(body.cephe)
+        .bind(&body.min_net_m2)
+        .bind(&body.min_budget)
+        .bind(&body.max_budget)
+        .bind(&body.currency)
+        .bind(&body.notes)
+        .bind(created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(lead)
+    }
+
+    pub async fn update_lead(
+        &self,
+        site_id: Uuid,
+        lead_id: Uuid,
+        body: &UpdateLead,
+    ) -> Result<Lead, sqlx::Error> {
+        sqlx::query_as::<_, Lead>(
+            "UPDATE leads SET
+                danisan_id = COALESCE($1, danisan_id),
+                listing_type = COALESCE($2, listing_type),
+                oda_sayisi = COALESCE($3, oda_sayisi),
+                blok = COALESCE($4, blok),
+                cephe = COALESCE($5, cephe),
+                min_net_m2 = COALESCE($6, min_net_m2),
+                min_budget = COALESCE($7, min_budget),
+                max_budget = COALESCE($8, max_budget),
+                currency = COALESCE($9, currency),
+                notes = COALESCE($10, notes)
+         WHERE id = $11 AND customer_id IN (SELECT id FROM customers WHERE site_id = $12)
+         RETURNING *",
+        )
+        .bind(body.danisan_id)
+        .bind(body.listing_type)
+        .bind(&body.oda_sayisi)
+        .bind(&body.blok)
+        .bind(body.cephe)
+        .bind(&body.min_net_m2)
+        .bind(&body.min_budget)
+        .bind(&body.max_budget)
+        .bind(&body.currency)
+        .bind(&body.notes)
+        .bind(lead_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn move_lead_stage(
+        &self,
+        site_id: Uuid,
+        lead_id: Uuid,
+        body: &MoveLeadStage,
+    ) -> Result<Lead, sqlx::Error> {
+        sqlx::query_as::<_, Lead>(
+            "UPDATE leads l SET
+                stage_id = s.id,
+                stage_changed_at = NOW(),
+                closed_at = CASE WHEN s.is_won OR s.is_lost THEN NOW() END,
+                lost_reason = CASE WHEN s.is_lost THEN $2 END
+         FROM lead_stages s
+         WHERE s.id = $1 AND l.id = $3
+           AND l.customer_id IN (SELECT id FROM customers WHERE site_id = $4)
+         RETURNING l.*",
+        )
+        .bind(body.stage_id)
+        .bind(&body.lost_reason)
+        .bind(lead_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+}
+
+fn push_lead_filters(query: &mut QueryBuilder<'_, Postgres>, site_id: Uuid, filter: &LeadFilter) {
+    query.push(" WHERE c.site_id = ").push_bind(site_id);
+
+    if let Some(stage_id) = filter.stage_id {
+        query.push(" AND l.stage_id = ").push_bind(stage_id);
+    }
+    if let Some(danisan_id) = filter.danisan_id {
+        query.push(" AND l.danisan_id = ").push_bind(danisan_id);
+    }
+    if let Some(customer_id) = filter.customer_id {
+        query.push(" AND l.customer_id = ").push_bind(customer_id);
+    }
+    if !filter.include_closed && filter.stage_id.is_none() {
+        query.push(" AND NOT (s.is_won OR s.is_lost)");
+    }
+}
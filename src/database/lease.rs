@@ -0,0 +1,104 @@
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{CreateLease, Lease, LeaseStatus, LeaseWithDetails, UpdateLeaseStatus},
+};
+
+impl Database {
+    pub async fn list_property_leases(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+    ) -> Result<Vec<LeaseWithDetails>, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+
+        sqlx::query_as::<_, LeaseWithDetails>(
+            "SELECT l.*, p.daire_no, t.ad_soyad AS kiraci_ad_soyad
+         FROM leases l
+         JOIN properties p ON p.id = l.property_id
+         JOIN tenants t ON t.id = l.tenant_id
+         WHERE l.property_id = $1
+         ORDER BY l.start_date DESC",
+        )
+        .bind(property_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn create_lease(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        body: &CreateLease,
+    ) -> Result<Lease, sqlx::Error> {
+        sqlx::query_as::<_, Lease>(
+            "INSERT INTO leases
+                (property_id, tenant_id, start_date, end_date, monthly_rent, currency, deposit, status)
+         SELECT p.id, t.id, $3, $4, $5, COALESCE($6, 'TRY'), $7, $8
+         FROM properties p
+         JOIN tenants t ON t.id = $2 AND t.site_id = p.site_id
+         WHERE p.id = $1 AND p.site_id = $9
+         RETURNING *",
+        )
+        .bind(property_id)
+        .bind(body.tenant_id)
+        .bind(body.start_date)
+        .bind(body.end_date)
+        .bind(&body.monthly_rent)
+        .bind(&body.currency)
+        .bind(&body.deposit)
+        .bind(body.status.unwrap_or(LeaseStatus::Active))
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn update_lease_status(
+        &self,
+        site_id: Uuid,
+        lease_id: Uuid,
+        body: &UpdateLeaseStatus,
+    ) -> Result<Lease, sqlx::Error> {
+        sqlx::query_as::<_, Lease>(
+            "UPDATE leases SET
+                status = $1,
+                end_date = CASE
+                    WHEN $1 IN ('ended', 'terminated') THEN COALESCE($2, LEAST(end_date, CURRENT_DATE))
+                    ELSE COALESCE($2, end_date)
+                END
+         WHERE id = $3 AND property_id IN (SELECT id FROM properties WHERE site_id = $4)
+         RETURNING *",
+        )
+        .bind(body.status)
+        .bind(body.end_date)
+        .bind(lease_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn list_expiring_leases(
+        &self,
+        site_id: Uuid,
+        days: i32,
+    ) -> Result<Vec<LeaseWithDetails>, sqlx::Error> {
+        sqlx::query_as::<_, LeaseWithDetails>(
+            "SELECT l.*, p.daire_no, t.ad_soyad AS kiraci_ad_soyad
+         FROM leases l
+         JOIN properties p ON p.id = l.property_id
+         JOIN tenants t ON t.id = l.tenant_id
+         WHERE p.site_id = $1
+           AND l.status = 'active'
+           AND l.end_date IS NOT NULL
+           AND l.end_date <= CURRENT_DATE + $2
+         ORDER BY l.end_date, p.daire_no",
+        )
+        .bind(site_id)
+        .bind(days)
+        .fetch_all(&self.pool)
+        .await
+    }
+}
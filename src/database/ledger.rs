@@ -0,0 +1,332 @@
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{
+        CreateCharge, CreatePayment, GenerateAidat, GenerateAidatResult, LedgerBalance,
+        LedgerCharge, LedgerChargeWithBalance, LedgerPayment, PropertyLedger, StatementEntry,
+    },
+};
+
+// Tahakkuka bağlanmamış ödemeler aynı daire, müşteri ve para birimindeki açık tahakkuklara
+// vade sırasıyla (en eskiden başlayarak) dağıtılır. Dağıtım daire bazında olduğundan kapsam
+// (tek daire ya da site) tahakkuk ve ödemelere baştan uygulanır.
+const PROPERTY_SCOPE: &str = "property_id = $1";
+const SITE_SCOPE: &str = "property_id IN (SELECT id FROM properties WHERE site_id = $1)";
+
+fn charges_with_balance(scope: &str) -> String {
+    format!(
+        "WITH linked AS (
+         SELECT c.*,
+             COALESCE((SELECT SUM(pm.amount) FROM ledger_payments pm WHERE pm.charge_id = c.id), 0)
+                 AS linked_paid
+         FROM ledger_charges c
+         WHERE c.{scope}
+     ),
+     open_charges AS (
+         SELECT l.*,
+             GREATEST(l.amount - l.linked_paid, 0) AS open_amount,
+             SUM(GREATEST(l.amount - l.linked_paid, 0)) OVER (
+                 PARTITION BY l.property_id, l.customer_id, l.currency
+                 ORDER BY l.due_date, l.created_at, l.id
+             ) AS open_through
+         FROM linked l
+     ),
+     unlinked AS (
+         SELECT property_id, customer_id, currency, SUM(amount) AS amount
+         FROM ledger_payments pm
+         WHERE pm.charge_id IS NULL AND pm.{scope}
+         GROUP BY property_id, customer_id, currency
+     ),
+     balances AS (
+         SELECT o.*,
+             o.linked_paid + LEAST(
+                 o.open_amount,
+                 GREATEST(COALESCE(u.amount, 0) - (o.open_through - o.open_amount), 0)
+             ) AS paid_amount
+         FROM open_charges o
+         LEFT JOIN unlinked u ON u.property_id = o.property_id
+             AND u.customer_id IS NOT DISTINCT FROM o.customer_id
+             AND u.currency = o.currency
+     )
+     SELECT c.id, c.property_id, c.customer_id, c.lease_id, c.kind, c.period, c.amount,
+         c.currency, c.due_date, c.description, c.created_by, c.created_at,
+         p.daire_no, c.paid_amount, c.amount - c.paid_amount AS remaining
+     FROM balances c
+     JOIN properties p ON p.id = c.property_id"
+    )
+}
+
+impl Database {
+    pub async fn property_ledger(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+    ) -> Result<PropertyLedger, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+
+        let charges = sqlx::query_as::<_, LedgerChargeWithBalance>(&format!(
+            "{}
+         ORDER BY c.due_date DESC, c.created_at DESC",
+            charges_with_balance(PROPERTY_SCOPE)
+        ))
+        .bind(property_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let payments = sqlx::query_as::<_, LedgerPayment>(
+            "SELECT * FROM ledger_payments
+         WHERE property_id = $1
+         ORDER BY paid_at DESC, created_at DESC",
+        )
+        .bind(property_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let balances = sqlx::query_as::<_, LedgerBalance>(
+            "SELECT currency,
+                SUM(charged) AS charged,
+                SUM(paid) AS paid,
+                SUM(charged) - SUM(paid) AS balance
+         FROM (
+             SELECT currency, amount AS charged, 0::NUMERIC AS paid
+             FROM ledger_charges WHERE property_id = $1
+             UNION ALL
+             SELECT currency, 0::NUMERIC, amount
+             FROM ledger_payments WHERE property_id = $1
+         ) entries
+         GROUP BY currency
+         ORDER BY currency",
+        )
+        .bind(property_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(PropertyLedger {
+            charges,
+            payments,
+            balances,
+        })
+    }
+
+    pub async fn get_ledger_charge(
+        &self,
+        site_id: Uuid,
+        charge_id: Uuid,
+    ) -> Result<LedgerCharge, sqlx::Error> {
+        sqlx::query_as::<_, LedgerCharge>(
+            "SELECT c.* FROM ledger_charges c
+             JOIN properties p ON p.id = c.property_id
+             WHERE c.id = $1 AND p.site_id = $2",
+        )
+        .bind(charge_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn create_ledger_charge(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        created_by: Uuid,
+        body: &CreateCharge,
+    ) -> Result<LedgerCharge, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+        if let Some(customer_id) = body.customer_id {
+            self.check_customers_site(site_id, &[customer_id]).await?;
+        }
+        if let Some(lease_id) = body.lease_id {
+            self.check_lease_site(site_id, lease_id).await?;
+            // Sözleşme tahakkukun dairesine ait olmalı
+            sqlx::query("SELECT 1 FROM leases WHERE id = $1 AND property_id = $2")
+                .bind(lease_id)
+                .bind(property_id)
+                .fetch_optional(&self.pool)
+                .await?
+                .ok_or(sqlx::Error::RowNotFound)?;
+        }
+
+        sqlx::query_as::<_, LedgerCharge>(
+            "INSERT INTO ledger_charges
+                (property_id, customer_id, lease_id, kind, period, amount, currency,
+                 due_date, description, created_by)
+         VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, 'TRY'), $8, $9, $10)
+         RETURNING *",
+        )
+        .bind(property_id)
+        .bind(body.customer_id)
+        .bind(body.lease_id)
+        .bind(body.kind)
+        .bind(body.period)
+        .bind(&body.amount)
+        .bind(&body.currency)
+        .bind(body.due_date)
+        .bind(&body.description)
+        .bind(created_by)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn create_ledger_payment(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        created_by: Uuid,
+        body: &CreatePayment,
+    ) -> Result<LedgerPayment, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+        if let Some(customer_id) = body.customer_id {
+            self.check_customers_site(site_id, &[customer_id]).await?;
+        }
+
+        sqlx::query_as::<_, LedgerPayment>(
+            "INSERT INTO ledger_payments
+                (property_id, customer_id, charge_id, amount, currency, paid_at,
+                 method, reference, created_by)
+         VALUES (
+             $1,
+             COALESCE($2, (SELECT customer_id FROM ledger_charges WHERE id = $3)),
+             $3,
+             $4,
+             COALESCE($5, (SELECT currency FROM ledger_charges WHERE id = $3), 'TRY'),
+             COALESCE($6, CURRENT_DATE),
+             $7,
+             $8,
+             $9
+         )
+         RETURNING *",
+        )
+        .bind(property_id)
+        .bind(body.customer_id)
+        .bind(body.charge_id)
+        .bind(&body.amount)
+        .bind(&body.currency)
+        .bind(body.paid_at)
+        .bind(&body.method)
+        .bind(&body.reference)
+        .bind(created_by)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn generate_aidat(
+        &self,
+        site_id: Uuid,
+        created_by: Uuid,
+        period: chrono::NaiveDate,
+        due_date: chrono::NaiveDate,
+        body: &GenerateAidat,
+    ) -> Result<GenerateAidatResult, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let total: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM properties WHERE site_id = $1 AND brut_m2 > 0",
+        )
+        .bind(site_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let result = sqlx::query(
+            "INSERT INTO ledger_charges
+                (property_id, customer_id, kind, period, amount, currency,
+                 due_date, description, created_by)
+         SELECT p.id,
+                (SELECT po.customer_id FROM current_property_owners po
+                 WHERE po.property_id = p.id
+                 ORDER BY po.share_percent DESC, po.created_at
+                 LIMIT 1),
+                'aidat', $1, ROUND(p.brut_m2 * $2, 2), COALESCE($3, 'TRY'), $4,
+                'Aidat ' || TO_CHAR($1::DATE, 'YYYY-MM'), $5
+         FROM properties p
+         WHERE p.site_id = $6 AND p.brut_m2 > 0
+         ON CONFLICT (property_id, period) WHERE kind = 'aidat' DO NOTHING",
+        )
+        .bind(period)
+        .bind(&body.rate_per_m2)
+        .bind(&body.currency)
+        .bind(due_date)
+        .bind(created_by)
+        .bind(site_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        let created = result.rows_affected();
+        Ok(GenerateAidatResult {
+            created,
+            skipped: (total as u64).saturating_sub(created),
+        })
+    }
+
+    pub async fn list_overdue_charges(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Vec<LedgerChargeWithBalance>, sqlx::Error> {
+        sqlx::query_as::<_, LedgerChargeWithBalance>(&format!(
+            "{}
+         WHERE c.due_date < CURRENT_DATE AND c.amount > c.paid_amount
+         ORDER BY c.due_date, p.daire_no",
+            charges_with_balance(SITE_SCOPE)
+        ))
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn customer_statement(
+        &self,
+        site_id: Uuid,
+        customer_id: Uuid,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<StatementEntry>, sqlx::Error> {
+        self.check_customers_site(site_id, &[customer_id]).await?;
+
+        sqlx::query_as::<_, StatementEntry>(
+            "SELECT entry_date, entry_type, daire_no, description, currency, debit, credit, balance
+         FROM (
+             SELECT e.*,
+                    SUM(e.debit - e.credit) OVER (
+                        PARTITION BY e.currency
+                        ORDER BY e.entry_date, e.created_at
+                    ) AS balance
+             FROM (
+                 SELECT COALESCE(c.period, c.due_date) AS entry_date,
+                        'charge' AS entry_type,
+                        p.daire_no,
+                        COALESCE(c.description, c.kind::TEXT) AS description,
+                        c.currency,
+                        c.amount AS debit,
+                        0::NUMERIC AS credit,
+                        c.created_at
+                 FROM ledger_charges c
+                 JOIN properties p ON p.id = c.property_id
+                 WHERE c.customer_id = $1
+                 UNION ALL
+                 SELECT pm.paid_at,
+                        'payment',
+                        p.daire_no,
+                        COALESCE(pm.reference, pm.method),
+                        pm.currency,
+                        0::NUMERIC,
+                        pm.amount,
+                        pm.created_at
+                 FROM ledger_payments pm
+                 JOIN properties p ON p.id = pm.property_id
+                 WHERE pm.customer_id = $1
+             ) e
+         ) s
+         WHERE ($2::DATE IS NULL OR entry_date >= $2)
+           AND ($3::DATE IS NULL OR entry_date <= $3)
+         ORDER BY currency, entry_date, created_at",
+        )
+        .bind(customer_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+    }
+}
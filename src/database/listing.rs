@@ -0,0 +1,270 @@
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{
+        CreateListing, Listing, ListingFilter, ListingStatus, ListingType, ListingWithProperty,
+        Page, Pagination, UpdateListing,
+    },
+};
+
+const LISTINGS_WITH_PROPERTY: &str = "SELECT l.*,
+        p.daire_no, p.blok, p.kat, p.oda_sayisi, p.brut_m2, p.net_m2, p.cephe,
+        u.name || ' ' || u.last_name AS danisan_ad_soyad,
+        ROUND(l.asking_price / NULLIF(p.net_m2, 0), 2) AS price_per_net_m2,
+        ROUND(l.asking_price / NULLIF(p.brut_m2, 0), 2) AS price_per_brut_m2
+     FROM listings l
+     JOIN properties p ON p.id = l.property_id
+     LEFT JOIN users u ON u.id = l.danisan_id";
+
+// Süresi dolan ilan durumu değişmese de yayında sayılmaz
+const NOT_EXPIRED: &str = "(l.expires_at IS NULL OR l.expires_at > NOW())";
+
+// Aynı daire ve türde süresi dolmuş yayındaki ilan kapatılır; yoksa "tek açık ilan" indeksi
+// yerine açılan ilanı engeller. $3 güncellenen ilanın kendisidir.
+const CLOSE_EXPIRED: &str = "UPDATE listings SET status = 'closed', closed_at = expires_at
+     WHERE property_id = $1 AND listing_type = $2 AND status = 'active'
+       AND expires_at <= NOW() AND id IS DISTINCT FROM $3";
+
+impl Database {
+    pub async fn list_listings(
+        &self,
+        site_id: Uuid,
+        filter: &ListingFilter,
+        pagination: &Pagination,
+    ) -> Result<Page<ListingWithProperty>, sqlx::Error> {
+        let mut count_query = QueryBuilder::<Postgres>::new(
+            "SELECT COUNT(*) FROM listings l JOIN properties p ON p.id = l.property_id",
+        );
+        push_listing_filters(&mut count_query, site_id, filter);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut query = QueryBuilder::<Postgres>::new(LISTINGS_WITH_PROPERTY);
+        push_listing_filters(&mut query, site_id, filter);
+        query
+            .push(" ORDER BY l.published_at DESC NULLS LAST, l.created_at DESC LIMIT ")
+            .push_bind(pagination.per_page())
+            .push(" OFFSET ")
+            .push_bind(pagination.offset());
+
+        let items = query
+            .build_query_as::<ListingWithProperty>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(Page {
+            items,
+            total,
+            page: pagination.page(),
+            per_page: pagination.per_page(),
+        })
+    }
+
+    pub async fn get_listing(
+        &self,
+        site_id: Uuid,
+        listing_id: Uuid,
+    ) -> Result<ListingWithProperty, sqlx::Error> {
+        sqlx::query_as::<_, ListingWithProperty>(&format!(
+            "{LISTINGS_WITH_PROPERTY}
+         WHERE l.id = $1 AND p.site_id = $2"
+        ))
+        .bind(listing_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn create_listing(
+        &self,
+        site_id: Uuid,
+        created_by: Uuid,
+        body: &CreateListing,
+    ) -> Result<Listing, sqlx::Error> {
+        self.check_property_site(site_id, body.property_id).await?;
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(CLOSE_EXPIRED)
+            .bind(body.property_id)
+            .bind(body.listing_type)
+            .bind(None::<Uuid>)
+            .execute(&mut *tx)
+            .await?;
+
+        let listing = sqlx::query_as::<_, Listing>(
+            "INSERT INTO listings
+                (property_id, listing_type, asking_price, currency, status, danisan_id,
+                 description, expires_at, published_at, created_by)
+         VALUES ($1, $2, $3, COALESCE($4, 'TRY'), $5, $6, $7, $8,
+                 CASE WHEN $5 = 'active' THEN NOW() END, $9)
+         RETURNING *",
+        )
+        .bind(body.property_id)
+        .bind(body.listing_type)
+        .bind(&body.asking_price)
+        .bind(&body.currency)
+        .bind(body.status.unwrap_or(ListingStatus::Draft))
+        .bind(body.danisan_id)
+        .bind(&body.description)
+        .bind(body.expires_at)
+        .bind(created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(listing)
+    }
+
+    pub async fn update_listing(
+        &self,
+        site_id: Uuid,
+        listing_id: Uuid,
+        body: &UpdateListing,
+    ) -> Result<Listing, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let (property_id, listing_type): (Uuid, ListingType) = sqlx::query_as(
+            "SELECT property_id, listing_type FROM listings
+             WHERE id = $1 AND property_id IN (SELECT id FROM properties WHERE site_id = $2)",
+        )
+        .bind(listing_id)
+        .bind(site_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+        sqlx::query(CLOSE_EXPIRED)
+            .bind(property_id)
+            .bind(listing_type)
+            .bind(listing_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let listing = sqlx::query_as::<_, Listing>(
+            "UPDATE listings SET
+                asking_price = COALESCE($1, asking_price),
+                currency = COALESCE($2, currency),
+                status = COALESCE($3, status),
+                danisan_id = COALESCE($4, danisan_id),
+                description = COALESCE($5, description),
+                expires_at = COALESCE($6, expires_at),
+                published_at = CASE
+                    WHEN $3 = 'active' THEN COALESCE(published_at, NOW())
+                    ELSE published_at
+                END,
+                closed_at = CASE
+                    WHEN $3 = 'closed' THEN COALESCE(closed_at, NOW())
+                    WHEN $3 IS NOT NULL THEN NULL
+                    ELSE closed_at
+                END
+         WHERE id = $7 AND property_id IN (SELECT id FROM properties WHERE site_id = $8)
+         RETURNING *",
+        )
+        .bind(&body.asking_price)
+        .bind(&body.currency)
+        .bind(body.status)
+        .bind(body.danisan_id)
+        .bind(&body.description)
+        .bind(body.expires_at)
+        .bind(listing_id)
+        .bind(site_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+        tx.commit().await?;
+        Ok(listing)
+    }
+
+    pub async fn list_active_listings(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Vec<ListingWithProperty>, sqlx::Error> {
+        sqlx::query_as::<_, ListingWithProperty>(&format!(
+            "{LISTINGS_WITH_PROPERTY}
+         WHERE p.site_id = $1 AND l.status = 'active' AND {NOT_EXPIRED}"
+        ))
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn list_unmatched_listings(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Vec<ListingWithProperty>, sqlx::Error> {
+        sqlx::query_as::<_, ListingWithProperty>(&format!(
+            "{LISTINGS_WITH_PROPERTY}
+         WHERE p.site_id = $1 AND l.status = 'active' AND {NOT_EXPIRED}
+           AND l.matched_at IS NULL
+         ORDER BY l.published_at"
+        ))
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn mark_listing_matched(&self, listing_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE listings SET matched_at = NOW() WHERE id = $1")
+            .bind(listing_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+fn push_listing_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    site_id: Uuid,
+    filter: &ListingFilter,
+) {
+    query.push(" WHERE p.site_id = ").push_bind(site_id);
+
+    if let Some(property_id) = filter.property_id {
+        query.push(" AND l.property_id = ").push_bind(property_id);
+    }
+    if let Some(listing_type) = filter.listing_type {
+        query.push(" AND l.listing_type = ").push_bind(listing_type);
+    }
+    if let Some(status) = filter.status {
+        query.push(" AND l.status = ").push_bind(status);
+        if status == ListingStatus::Active {
+            query.push(" AND ").push(NOT_EXPIRED);
+        }
+    }
+    if let Some(danisan_id) = filter.danisan_id {
+        query.push(" AND l.danisan_id = ").push_bind(danisan_id);
+    }
+    if let Some(blok) = &filter.blok {
+        query.push(" AND p.blok = ").push_bind(blok.clone());
+    }
+    if let Some(oda_sayisi) = &filter.oda_sayisi {
+        query
+            .push(" AND p.oda_sayisi = ")
+            .push_bind(oda_sayisi.clone());
+    }
+    if let Some(cephe) = &filter.cephe {
+        query.push(" AND p.cephe::TEXT = ").push_bind(cephe.clone());
+    }
+    if let Some(currency) = &filter.currency {
+        query.push(" AND l.currency = ").push_bind(currency.clone());
+    }
+    if let Some(min) = &filter.min_price {
+        query.push(" AND l.asking_price >= ").push_bind(min.clone());
+    }
+    if let Some(max) = &filter.max_price {
+        query.push(" AND l.asking_price <= ").push_bind(max.clone());
+    }
+    if let Some(min) = &filter.min_net_m2 {
+        query.push(" AND p.net_m2 >= ").push_bind(min.clone());
+    }
+    if let Some(max) = &filter.max_net_m2 {
+        query.push(" AND p.net_m2 <= ").push_bind(max.clone());
+    }
+}
@@ -8,9 +8,26 @@ pub enum DatabaseError {
     MigrationFailed,
 }
 
+mod appointment;
 mod customer;
+mod document;
+mod export;
+mod import;
+pub use import::ImportSession;
+mod lead;
+mod lease;
+mod ledger;
+mod listing;
+mod notification;
+mod ownership;
+mod photo;
 mod property;
 mod reminder;
+mod report;
+mod site;
+mod stats;
+mod tenant;
+mod ticket;
 mod user;
 
 #[derive(Clone)]
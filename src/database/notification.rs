@@ -0,0 +1,67 @@
+use uuid::Uuid;
+
+use crate::{database::Database, models::Notification};
+
+impl Database {
+    pub async fn create_match_notification(
+        &self,
+        site_id: Uuid,
+        user_id: Uuid,
+        lead_id: Uuid,
+        listing_id: Uuid,
+        title: &str,
+        body: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO notifications (user_id, lead_id, listing_id, title, body, site_id)
+         VALUES ($1, $2, $3, $4, $5, $6)
+         ON CONFLICT (user_id, lead_id, listing_id) DO NOTHING",
+        )
+        .bind(user_id)
+        .bind(lead_id)
+        .bind(listing_id)
+        .bind(title)
+        .bind(body)
+        .bind(site_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    pub async fn list_notifications(
+        &self,
+        site_id: Uuid,
+        user_id: Uuid,
+        unread_only: bool,
+    ) -> Result<Vec<Notification>, sqlx::Error> {
+        sqlx::query_as::<_, Notification>(
+            "SELECT * FROM notifications
+         WHERE user_id = $1 AND (NOT $2 OR read_at IS NULL)
+           AND (site_id IS NULL OR site_id = $3)
+         ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .bind(unread_only)
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn mark_notification_read(
+        &self,
+        notification_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Notification, sqlx::Error> {
+        sqlx::query_as::<_, Notification>(
+            "UPDATE notifications SET read_at = COALESCE(read_at, NOW())
+         WHERE id = $1 AND user_id = $2
+         RETURNING *",
+        )
+        .bind(notification_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+}
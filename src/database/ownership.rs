@@ -0,0 +1,237 @@
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{
+        CreatePropertyTransfer, NewPropertyOwner, PropertyOwner, PropertyTransfer,
+        PropertyTransferDetail,
+    },
+};
+
+impl Database {
+    pub async fn list_property_owners(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        include_past: bool,
+    ) -> Result<Vec<PropertyOwner>, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+
+        sqlx::query_as::<_, PropertyOwner>(
+            "SELECT po.id, po.property_id, po.customer_id, c.ad_soyad,
+                po.share_percent, po.start_date, po.end_date
+         FROM property_owners po
+         JOIN customers c ON c.id = po.customer_id
+         WHERE po.property_id = $1
+           AND ($2 OR ((po.end_date IS NULL OR po.end_date > CURRENT_DATE)
+                   AND (po.start_date IS NULL OR po.start_date <= CURRENT_DATE)))
+         ORDER BY po.end_date DESC NULLS FIRST, po.share_percent DESC, c.ad_soyad",
+        )
+        .bind(property_id)
+        .bind(include_past)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn set_property_owners(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        owners: &[NewPropertyOwner],
+    ) -> Result<(), sqlx::Error> {
+        let customer_ids: Vec<Uuid> = owners.iter().map(|o| o.customer_id).collect();
+        self.check_property_site(site_id, property_id).await?;
+        self.check_customers_site(site_id, &customer_ids).await?;
+
+        let mut tx = self.pool.begin().await?;
+        replace_property_owners(&mut tx, property_id, owners).await?;
+        tx.commit().await
+    }
+
+    pub async fn create_property_transfer(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        created_by: Uuid,
+        body: &CreatePropertyTransfer,
+    ) -> Result<PropertyTransferDetail, sqlx::Error> {
+        let customer_ids: Vec<Uuid> = body.owners.iter().map(|o| o.customer_id).collect();
+        self.check_property_site(site_id, property_id).await?;
+        self.check_customers_site(site_id, &customer_ids).await?;
+
+        let mut tx = self.pool.begin().await?;
+
+        let transfer = sqlx::query_as::<_, PropertyTransfer>(
+            "INSERT INTO property_transfers
+                (property_id, sale_date, sale_price, currency, danisan_id, notes, created_by)
+         VALUES ($1, $2, $3, COALESCE($4, 'TRY'), $5, $6, $7)
+         RETURNING *",
+        )
+        .bind(property_id)
+        .bind(body.sale_date)
+        .bind(&body.sale_price)
+        .bind(&body.currency)
+        .bind(body.danisan_id)
+        .bind(&body.notes)
+        .bind(created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "UPDATE property_owners SET end_date = $2, transfer_out_id = $3
+         WHERE property_id = $1 AND (end_date IS NULL OR end_date > $2)",
+        )
+        .bind(property_id)
+        .bind(body.sale_date)
+        .bind(transfer.id)
+        .execute(&mut *tx)
+        .await?;
+
+        for owner in &body.owners {
+            sqlx::query(
+                "INSERT INTO property_owners
+                    (property_id, customer_id, share_percent, start_date, transfer_in_id)
+             VALUES ($1, $2, $3, $4, $5)",
+            )
+            .bind(property_id)
+            .bind(owner.customer_id)
+            .bind(&owner.share_percent)
+            .bind(body.sale_date)
+            .bind(transfer.id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        self.property_transfer_detail(transfer).await
+    }
+
+    // Devirle sonlandırılacak sahipliklerin en geç başlangıç tarihi; satış tarihi bundan önce olamaz
+    pub async fn latest_open_owner_start(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        sale_date: chrono::NaiveDate,
+    ) -> Result<Option<chrono::NaiveDate>, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+
+        sqlx::query_scalar(
+            "SELECT MAX(start_date) FROM property_owners
+             WHERE property_id = $1 AND (end_date IS NULL OR end_date > $2)",
+        )
+        .bind(property_id)
+        .bind(sale_date)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn list_property_transfers(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+    ) -> Result<Vec<PropertyTransferDetail>, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+
+        let transfers = sqlx::query_as::<_, PropertyTransfer>(
+            "SELECT * FROM property_transfers
+         WHERE property_id = $1
+         ORDER BY sale_date DESC, created_at DESC",
+        )
+        .bind(property_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut result = Vec::with_capacity(transfers.len());
+        for transfer in transfers {
+            result.push(self.property_transfer_detail(transfer).await?);
+        }
+
+        Ok(result)
+    }
+
+    async fn property_transfer_detail(
+        &self,
+        transfer: PropertyTransfer,
+    ) -> Result<PropertyTransferDetail, sqlx::Error> {
+        let owners = |column: &str| {
+            format!(
+                "SELECT po.id, po.property_id, po.customer_id, c.ad_soyad,
+                    po.share_percent, po.start_date, po.end_date
+             FROM property_owners po
+             JOIN customers c ON c.id = po.customer_id
+             WHERE po.{} = $1
+             ORDER BY po.share_percent DESC, c.ad_soyad",
+                column
+            )
+        };
+
+        let previous_owners = sqlx::query_as::<_, PropertyOwner>(&owners("transfer_out_id"))
+            .bind(transfer.id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let new_owners = sqlx::query_as::<_, PropertyOwner>(&owners("transfer_in_id"))
+            .bind(transfer.id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(PropertyTransferDetail {
+            transfer,
+            previous_owners,
+            new_owners,
+        })
+    }
+}
+
+// Güncel sahipleri verilen listeyle değiştirir; listede olmayanların sahipliği bugün itibarıyla biter.
+// İleri tarihli devirle henüz başlamamış sahipliklere dokunulmaz.
+pub(super) async fn replace_property_owners(
+    conn: &mut PgConnection,
+    property_id: Uuid,
+    owners: &[NewPropertyOwner],
+) -> Result<(), sqlx::Error> {
+    let customer_ids: Vec<Uuid> = owners.iter().map(|o| o.customer_id).collect();
+
+    sqlx::query(
+        "UPDATE property_owners SET end_date = CURRENT_DATE
+         WHERE property_id = $1
+           AND (end_date IS NULL OR end_date > CURRENT_DATE)
+           AND (start_date IS NULL OR start_date <= CURRENT_DATE)
+           AND customer_id <> ALL($2)",
+    )
+    .bind(property_id)
+    .bind(&customer_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    for owner in owners {
+        let updated = sqlx::query(
+            "UPDATE property_owners SET share_percent = $3
+             WHERE property_id = $1 AND customer_id = $2
+               AND (end_date IS NULL OR end_date > CURRENT_DATE)
+               AND (start_date IS NULL OR start_date <= CURRENT_DATE)",
+        )
+        .bind(property_id)
+        .bind(owner.customer_id)
+        .bind(&owner.share_percent)
+        .execute(&mut *conn)
+        .await?;
+
+        if updated.rows_affected() == 0 {
+            sqlx::query(
+                "INSERT INTO property_owners (property_id, customer_id, share_percent, start_date)
+                 VALUES ($1, $2, $3, COALESCE($4, CURRENT_DATE))",
+            )
+            .bind(property_id)
+            .bind(owner.customer_id)
+            .bind(&owner.share_percent)
+            .bind(owner.start_date)
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{NewPropertyPhoto, PropertyPhoto},
+    photos,
+};
+
+const PHOTO_COLUMNS: &str = "id, property_id, position, is_cover, file_name, width, height,
+    checksum, web_key, thumb_key, uploaded_by, created_at";
+
+fn with_urls(mut photo: PropertyPhoto) -> PropertyPhoto {
+    photo.web_url = photos::signed_url(photo.id, "web");
+    photo.thumbnail_url = photos::signed_url(photo.id, "thumb");
+    photo
+}
+
+impl Database {
+    pub async fn list_property_photos(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+    ) -> Result<Vec<PropertyPhoto>, sqlx::Error> {
+        sqlx::query_as::<_, PropertyPhoto>(&format!(
+            "SELECT {} FROM property_photos
+             WHERE property_id = $1
+               AND property_id IN (SELECT id FROM properties WHERE site_id = $2)
+             ORDER BY position",
+            PHOTO_COLUMNS
+        ))
+        .bind(property_id)
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+        .map(|photos| photos.into_iter().map(with_urls).collect())
+    }
+
+    pub async fn get_photo(
+        &self,
+        site_id: Uuid,
+        photo_id: Uuid,
+    ) -> Result<PropertyPhoto, sqlx::Error> {
+        sqlx::query_as::<_, PropertyPhoto>(&format!(
+            "SELECT {} FROM property_photos
+             WHERE id = $1 AND property_id IN (SELECT id FROM properties WHERE site_id = $2)",
+            PHOTO_COLUMNS
+        ))
+        .bind(photo_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .map(with_urls)
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    // İmzalı adres doğrulandıktan sonra kullanılır; site kontrolünü imza üstlenir.
+    pub async fn get_photo_by_id(&self, photo_id: Uuid) -> Result<PropertyPhoto, sqlx::Error> {
+        sqlx::query_as::<_, PropertyPhoto>(&format!(
+            "SELECT {} FROM property_photos WHERE id = $1",
+            PHOTO_COLUMNS
+        ))
+        .bind(photo_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    // Bir yüklemedeki tüm fotoğraflar tek işlemde eklenir; biri başarısız olursa hiçbiri kalmaz.
+    pub async fn create_photos(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        photos: &[NewPropertyPhoto],
+    ) -> Result<Vec<PropertyPhoto>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("SELECT id FROM properties WHERE id = $1 AND site_id = $2 FOR UPDATE")
+            .bind(property_id)
+            .bind(site_id)
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+        let mut created = Vec::with_capacity(photos.len());
+        for photo in photos {
+            let row = sqlx::query_as::<_, PropertyPhoto>(&format!(
+                "INSERT INTO property_photos
+                    (property_id, position, file_name, width, height, checksum, web_key, thumb_key, uploaded_by)
+             VALUES (
+                $1,
+                (SELECT COALESCE(MAX(position) + 1, 0) FROM property_photos WHERE property_id = $1),
+                $2, $3, $4, $5, $6, $7, $8
+             )
+             RETURNING {}",
+                PHOTO_COLUMNS
+            ))
+            .bind(property_id)
+            .bind(&photo.file_name)
+            .bind(photo.width)
+            .bind(photo.height)
+            .bind(&photo.checksum)
+            .bind(&photo.web_key)
+            .bind(&photo.thumb_key)
+            .bind(photo.uploaded_by)
+            .fetch_one(&mut *tx)
+            .await?;
+            created.push(with_urls(row));
+        }
+
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    pub async fn reorder_property_photos(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        photo_ids: &[Uuid],
+    ) -> Result<Vec<PropertyPhoto>, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("SET CONSTRAINTS property_photos_position_key DEFERRED")
+            .execute(&mut *tx)
+            .await?;
+
+        for (position, photo_id) in photo_ids.iter().enumerate() {
+            let result = sqlx::query(
+                "UPDATE property_photos SET position = $1 WHERE id = $2 AND property_id = $3",
+            )
+            .bind(position as i32)
+            .bind(photo_id)
+            .bind(property_id)
+            .execute(&mut *tx)
+            .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(sqlx::Error::RowNotFound);
+            }
+        }
+
+        tx.commit().await?;
+        self.list_property_photos(site_id, property_id).await
+    }
+
+    pub async fn set_cover_photo(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+        photo_id: Uuid,
+    ) -> Result<PropertyPhoto, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            "UPDATE property_photos SET is_cover = FALSE WHERE property_id = $1 AND is_cover",
+        )
+        .bind(property_id)
+        .execute(&mut *tx)
+        .await?;
+
+        let photo = sqlx::query_as::<_, PropertyPhoto>(&format!(
+            "UPDATE property_photos SET is_cover = TRUE
+             WHERE id = $1 AND property_id = $2
+             RETURNING {}",
+            PHOTO_COLUMNS
+        ))
+        .bind(photo_id)
+        .bind(property_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+        tx.commit().await?;
+        Ok(with_urls(photo))
+    }
+
+    pub async fn delete_photo(
+        &self,
+        site_id: Uuid,
+        photo_id: Uuid,
+    ) -> Result<PropertyPhoto, sqlx::Error> {
+        sqlx::query_as::<_, PropertyPhoto>(&format!(
+            "DELETE FROM property_photos
+             WHERE id = $1 AND property_id IN (SELECT id FROM properties WHERE site_id = $2)
+             RETURNING {}",
+            PHOTO_COLUMNS
+        ))
+        .bind(photo_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+}
@@ -1,92 +1,168 @@
 use crate::database::Database;
-use crate::models::{CreateProperty, Property, PropertyWithCustomer};
+use crate::models::{
+    FacetCount, Page, Pagination, PropertyFacets, PropertyFilter, PropertyWithCustomer,
+};
+use crate::photos;
 
+use sqlx::{Postgres, QueryBuilder};
 use uuid::Uuid;
 
 impl Database {
-    pub async fn upsert_property(&self, prop: &CreateProperty) -> Result<bool, sqlx::Error> {
-        let result = sqlx::query(
-        "INSERT INTO properties (daire_no, blok, kat, kapi_no, oda_sayisi, daire_tipi, brut_m2, net_m2, balkon_m2, cephe, kiraci_var_mi)
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-         ON CONFLICT (daire_no) DO UPDATE SET
-             blok = EXCLUDED.blok,
-             kat = EXCLUDED.kat,
-             kapi_no = EXCLUDED.kapi_no,
-             oda_sayisi = EXCLUDED.oda_sayisi,
-             daire_tipi = EXCLUDED.daire_tipi,
-             brut_m2 = EXCLUDED.brut_m2,
-             net_m2 = EXCLUDED.net_m2,
-             balkon_m2 = EXCLUDED.balkon_m2,
-             cephe = EXCLUDED.cephe,
-             kiraci_var_mi = EXCLUDED.kiraci_var_mi"
-        )
-        .bind(&prop.daire_no)
-        .bind(&prop.blok)
-        .bind(&prop.kat)
-        .bind(prop.kapi_no)
-        .bind(&prop.oda_sayisi)
-        .bind(&prop.daire_tipi)
-        .bind(&prop.brut_m2)
-        .bind(&prop.net_m2)
-        .bind(&prop.balkon_m2)
-        .bind(&prop.cephe)
-        .bind(prop.kiraci_var_mi)
-        .execute(&self.pool)
-        .await?;
+    pub async fn list_properties(
+        &self,
+        site_id: Uuid,
+        filter: &PropertyFilter,
+        pagination: &Pagination,
+    ) -> Result<Page<PropertyWithCustomer>, sqlx::Error> {
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM properties p");
+        push_property_filters(&mut count_query, site_id, filter);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut query = QueryBuilder::<Postgres>::new(
+            "SELECT
+            p.id, p.daire_no, p.blok, p.kat, p.kapi_no, p.daire_tipi,
+            p.oda_sayisi, p.brut_m2, p.net_m2, p.balkon_m2, p.cephe,
+            p.kiraci_var_mi,
+            COALESCE(
+                ARRAY_AGG(c.id ORDER BY c.ad_soyad) FILTER (WHERE c.id IS NOT NULL),
+                '{}'
+            ) AS sahip_idler,
+            STRING_AGG(c.ad_soyad, ', ' ORDER BY c.ad_soyad) AS sahip_ad_soyad,
+            (SELECT ph.id
+               FROM property_photos ph
+              WHERE ph.property_id = p.id
+              ORDER BY ph.is_cover DESC, ph.position
+              LIMIT 1) AS cover_photo_id
+         FROM properties p
+         LEFT JOIN current_property_owners po ON po.property_id = p.id
+         LEFT JOIN customers c ON c.id = po.customer_id",
+        );
+        push_property_filters(&mut query, site_id, filter);
+        query
+            .push(" GROUP BY p.id ORDER BY p.daire_no LIMIT ")
+            .push_bind(pagination.per_page())
+            .push(" OFFSET ")
+            .push_bind(pagination.offset());
+
+        let mut items = query
+            .build_query_as::<PropertyWithCustomer>()
+            .fetch_all(&self.pool)
+            .await?;
+        for item in &mut items {
+            item.cover_thumbnail_url = item
+                .cover_photo_id
+                .map(|id| photos::signed_url(id, "thumb"));
+        }
 
-        Ok(result.rows_affected() == 1)
+        Ok(Page {
+            items,
+            total,
+            page: pagination.page(),
+            per_page: pagination.per_page(),
+        })
     }
 
-    pub async fn get_property_by_daire_no(
+    pub async fn property_facets(
         &self,
-        daire_no: &str,
-    ) -> Result<Option<Property>, sqlx::Error> {
-        sqlx::query_as::<_, Property>("SELECT * FROM properties WHERE daire_no = $1")
-            .bind(daire_no)
-            .fetch_optional(&self.pool)
-            .await
+        site_id: Uuid,
+        filter: &PropertyFilter,
+    ) -> Result<PropertyFacets, sqlx::Error> {
+        Ok(PropertyFacets {
+            blok: self.property_facet(site_id, "p.blok", filter).await?,
+            oda_sayisi: self.property_facet(site_id, "p.oda_sayisi", filter).await?,
+            cephe: self
+                .property_facet(site_id, "p.cephe::TEXT", filter)
+                .await?,
+        })
     }
 
-    pub async fn set_property_owner(
+    async fn property_facet(
         &self,
-        property_id: Uuid,
-        customer_id: Uuid,
-    ) -> Result<(), sqlx::Error> {
-        sqlx::query("UPDATE properties SET sahip_id = $1 WHERE id = $2")
-            .bind(customer_id)
-            .bind(property_id)
-            .execute(&self.pool)
-            .await?;
-        Ok(())
+        site_id: Uuid,
+        column: &str,
+        filter: &PropertyFilter,
+    ) -> Result<Vec<FacetCount>, sqlx::Error> {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT ");
+        query
+            .push(column)
+            .push(" AS value, COUNT(*) AS count FROM properties p");
+        push_property_filters(&mut query, site_id, filter);
+        query.push(" GROUP BY 1 ORDER BY 1");
+
+        query
+            .build_query_as::<FacetCount>()
+            .fetch_all(&self.pool)
+            .await
     }
+}
 
-    pub async fn set_kiraci_var_mi(
-        &self,
-        property_id: Uuid,
-        kiraci_var_mi: bool,
-    ) -> Result<(), sqlx::Error> {
-        sqlx::query("UPDATE properties SET kiraci_var_mi = $1 WHERE id = $2")
-            .bind(kiraci_var_mi)
-            .bind(property_id)
-            .execute(&self.pool)
-            .await?;
-        Ok(())
+pub(super) fn push_property_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    site_id: Uuid,
+    filter: &PropertyFilter,
+) {
+    query.push(" WHERE p.site_id = ").push_bind(site_id);
+
+    if let Some(daire_no) = &filter.daire_no {
+        query
+            .push(" AND p.daire_no = ")
+            .push_bind(daire_no.to_string());
     }
 
-    pub async fn list_properties(&self) -> Result<Vec<PropertyWithCustomer>, sqlx::Error> {
-        let data = sqlx::query_as::<_, PropertyWithCustomer>(
-            "SELECT
-            p.id, p.daire_no, p.blok, p.kat, p.kapi_no, p.daire_tipi,
-            p.oda_sayisi, p.brut_m2, p.net_m2, p.balkon_m2, p.cephe,
-            p.kiraci_var_mi, p.sahip_id,
-            c.ad_soyad AS sahip_ad_soyad
-         FROM properties p
-         LEFT JOIN customers c ON c.id = p.sahip_id
-         ORDER BY p.daire_no",
-        )
-        .fetch_all(&self.pool)
-        .await?;
+    if let Some(block_id) = filter.block_id {
+        query.push(" AND p.block_id = ").push_bind(block_id);
+    }
 
-        Ok(data)
+    if let Some(blok) = &filter.blok {
+        query.push(" AND p.blok = ").push_bind(blok.clone());
+    }
+    if let Some(kat) = &filter.kat {
+        query.push(" AND p.kat = ").push_bind(kat.clone());
+    }
+    if let Some(oda_sayisi) = &filter.oda_sayisi {
+        query
+            .push(" AND p.oda_sayisi = ")
+            .push_bind(oda_sayisi.clone());
+    }
+    if let Some(daire_tipi) = &filter.daire_tipi {
+        query
+            .push(" AND p.daire_tipi = ")
+            .push_bind(daire_tipi.clone());
+    }
+    if let Some(cephe) = &filter.cephe {
+        query.push(" AND p.cephe::TEXT = ").push_bind(cephe.clone());
+    }
+    if let Some(min) = &filter.min_brut_m2 {
+        query.push(" AND p.brut_m2 >= ").push_bind(min.clone());
+    }
+    if let Some(max) = &filter.max_brut_m2 {
+        query.push(" AND p.brut_m2 <= ").push_bind(max.clone());
+    }
+    if let Some(min) = &filter.min_net_m2 {
+        query.push(" AND p.net_m2 >= ").push_bind(min.clone());
+    }
+    if let Some(max) = &filter.max_net_m2 {
+        query.push(" AND p.net_m2 <= ").push_bind(max.clone());
+    }
+    if let Some(kiraci_var_mi) = filter.kiraci_var_mi {
+        query
+            .push(" AND p.kiraci_var_mi = ")
+            .push_bind(kiraci_var_mi);
+    }
+    match filter.sahip_var_mi {
+        Some(true) => {
+            query.push(
+                " AND EXISTS (SELECT 1 FROM current_property_owners po WHERE po.property_id = p.id)",
+            );
+        }
+        Some(false) => {
+            query.push(
+                " AND NOT EXISTS (SELECT 1 FROM current_property_owners po WHERE po.property_id = p.id)",
+            );
+        }
+        None => {}
     }
 }
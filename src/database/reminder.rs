@@ -8,20 +8,39 @@ use crate::{
 impl Database {
     pub async fn create_reminder(
         &self,
+        site_id: Uuid,
         customer_id: Uuid,
         created_by: Uuid,
         body: &CreateReminder,
     ) -> Result<Reminder, sqlx::Error> {
         sqlx::query_as::<_, Reminder>(
-            "INSERT INTO reminders (title, reminder_date, created_by, customer_id)
-         VALUES ($1, $2, $3, $4)
+            "INSERT INTO reminders (title, reminder_date, created_by, customer_id, site_id)
+         VALUES ($1, $2, $3, $4, $5)
          RETURNING *",
         )
         .bind(&body.title)
         .bind(body.reminder_date)
         .bind(created_by)
         .bind(customer_id)
+        .bind(site_id)
         .fetch_one(&self.pool)
         .await
     }
+
+    pub async fn complete_reminder(
+        &self,
+        site_id: Uuid,
+        reminder_id: Uuid,
+    ) -> Result<Reminder, sqlx::Error> {
+        sqlx::query_as::<_, Reminder>(
+            "UPDATE reminders SET completed_at = COALESCE(completed_at, NOW())
+         WHERE id = $1 AND site_id = $2
+         RETURNING *",
+        )
+        .bind(reminder_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
 }
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{ConsultantPerformance, ConsultantReport, WeeklyCount},
+};
+
+// Tarih aralığı İstanbul saatine göre, iki uç dahil
+const RANGE_START: &str = "($2::DATE)::TIMESTAMP AT TIME ZONE 'Europe/Istanbul'";
+const RANGE_END: &str = "($3::DATE + 1)::TIMESTAMP AT TIME ZONE 'Europe/Istanbul'";
+
+impl Database {
+    pub async fn consultant_report(
+        &self,
+        site_id: Uuid,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<ConsultantReport, sqlx::Error> {
+        let mut consultants = sqlx::query_as::<_, ConsultantPerformance>(&format!(
+            "WITH consultants AS (
+                SELECT u.id, u.name || ' ' || u.last_name AS ad_soyad
+                FROM users u
+                WHERE EXISTS (
+                        SELECT 1 FROM site_members m
+                        WHERE m.user_id = u.id AND m.site_id = $1 AND m.role <> 'viewer'
+                    )
+                   OR EXISTS (
+                        SELECT 1 FROM customers c WHERE c.danisan_id = u.id AND c.site_id = $1
+                    )
+             )
+             SELECT d.id AS danisan_id, d.ad_soyad,
+                cu.assigned AS assigned_customers,
+                cu.nc30 AS not_contacted_30,
+                cu.nc60 AS not_contacted_60,
+                cu.nc90 AS not_contacted_90,
+                r.completed AS reminders_completed,
+                r.overdue AS reminders_overdue,
+                n.written AS notes_written,
+                w.closed AS deals_closed
+             FROM consultants d
+             CROSS JOIN LATERAL (
+                SELECT COUNT(*) AS assigned,
+                    COUNT(*) FILTER (WHERE en_son_gorusuldu IS NULL
+                        OR en_son_gorusuldu < NOW() - INTERVAL '30 days') AS nc30,
+                    COUNT(*) FILTER (WHERE en_son_gorusuldu IS NULL
+                        OR en_son_gorusuldu < NOW() - INTERVAL '60 days') AS nc60,
+                    COUNT(*) FILTER (WHERE en_son_gorusuldu IS NULL
+                        OR en_son_gorusuldu < NOW() - INTERVAL '90 days') AS nc90
+                FROM customers
+                WHERE danisan_id = d.id AND site_id = $1
+             ) cu
+             CROSS JOIN LATERAL (
+                SELECT
+                    COUNT(*) FILTER (WHERE completed_at >= {start} AND completed_at < {end})
+                        AS completed,
+                    COUNT(*) FILTER (WHERE completed_at IS NULL AND reminder_date < NOW())
+                        AS overdue
+                FROM reminders
+                WHERE created_by = d.id AND site_id = $1
+             ) r
+             CROSS JOIN LATERAL (
+                SELECT COUNT(*) AS written
+                FROM customer_notes cn
+                JOIN customers c ON c.id = cn.customer_id
+                WHERE cn.created_by = d.id AND c.site_id = $1
+                  AND cn.created_at >= {start} AND cn.created_at < {end}
+             ) n
+             CROSS JOIN LATERAL (
+                SELECT COUNT(*) AS closed
+                FROM leads l
+                JOIN lead_stages s ON s.id = l.stage_id
+                JOIN customers c ON c.id = l.customer_id
+                WHERE l.danisan_id = d.id AND c.site_id = $1 AND s.is_won
+                  AND l.closed_at >= {start} AND l.closed_at < {end}
+             ) w
+             ORDER BY d.ad_soyad",
+            start = RANGE_START,
+            end = RANGE_END,
+        ))
+        .bind(site_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let weekly: Vec<(Uuid, chrono::NaiveDate, i64)> = sqlx::query_as(&format!(
+            "SELECT cn.created_by,
+                date_trunc('week', cn.created_at AT TIME ZONE 'Europe/Istanbul')::DATE,
+                COUNT(*)
+             FROM customer_notes cn
+             JOIN customers c ON c.id = cn.customer_id
+             WHERE c.site_id = $1
+               AND cn.created_at >= {} AND cn.created_at < {}
+             GROUP BY 1, 2",
+            RANGE_START, RANGE_END,
+        ))
+        .bind(site_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let weekly: HashMap<(Uuid, chrono::NaiveDate), i64> = weekly
+            .into_iter()
+            .map(|(user_id, week, count)| ((user_id, week), count))
+            .collect();
+
+        // Not yazılmayan haftalar da sıfırla listelensin
+        let weeks = week_starts(from, to);
+        for consultant in &mut consultants {
+            consultant.notes_per_week = weeks
+                .iter()
+                .map(|&week_start| WeeklyCount {
+                    week_start,
+                    count: weekly
+                        .get(&(consultant.danisan_id, week_start))
+                        .copied()
+                        .unwrap_or(0),
+                })
+                .collect();
+        }
+
+        Ok(ConsultantReport {
+            from,
+            to,
+            consultants,
+        })
+    }
+}
+
+fn week_starts(from: chrono::NaiveDate, to: chrono::NaiveDate) -> Vec<chrono::NaiveDate> {
+    use chrono::Datelike;
+
+    let mut week = from - chrono::Days::new(from.weekday().num_days_from_monday() as u64);
+    let mut weeks = Vec::new();
+    while week <= to {
+        weeks.push(week);
+        week = week + chrono::Days::new(7);
+    }
+    weeks
+}
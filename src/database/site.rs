@@ -0,0 +1,340 @@
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{
+        Block, BlockNode, CreateBlock, CreateSite, FloorNode, Occupancy, Site, SiteMember,
+        SiteRole, SiteTree, SiteWithRole, UnitSummary,
+    },
+};
+
+impl Database {
+    pub async fn list_sites(&self) -> Result<Vec<Site>, sqlx::Error> {
+        sqlx::query_as::<_, Site>("SELECT * FROM sites ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn list_user_sites(&self, user_id: Uuid) -> Result<Vec<SiteWithRole>, sqlx::Error> {
+        sqlx::query_as::<_, SiteWithRole>(
+            "SELECT s.*,
+                CASE WHEN u.is_admin THEN 'manager'::site_role_enum ELSE m.role END AS role
+             FROM sites s
+             JOIN users u ON u.id = $1
+             LEFT JOIN site_members m ON m.site_id = s.id AND m.user_id = u.id
+             WHERE u.is_admin OR m.user_id IS NOT NULL
+             ORDER BY s.name",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn site_role(
+        &self,
+        site_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<SiteRole>, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT CASE WHEN u.is_admin THEN 'manager'::site_role_enum ELSE m.role END
+             FROM users u
+             JOIN sites s ON s.id = $1
+             LEFT JOIN site_members m ON m.site_id = s.id AND m.user_id = u.id
+             WHERE u.id = $2 AND (u.is_admin OR m.user_id IS NOT NULL)",
+        )
+        .bind(site_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+    }
+
+    pub async fn list_site_members(&self, site_id: Uuid) -> Result<Vec<SiteMember>, sqlx::Error> {
+        sqlx::query_as::<_, SiteMember>(
+            "SELECT m.user_id, u.name, u.last_name, u.email, m.role, m.created_at
+             FROM site_members m
+             JOIN users u ON u.id = m.user_id
+             WHERE m.site_id = $1
+             ORDER BY u.name, u.last_name",
+        )
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn set_site_member(
+        &self,
+        site_id: Uuid,
+        user_id: Uuid,
+        role: SiteRole,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO site_members (site_id, user_id, role) VALUES ($1, $2, $3)
+             ON CONFLICT (site_id, user_id) DO UPDATE SET role = EXCLUDED.role",
+        )
+        .bind(site_id)
+        .bind(user_id)
+        .bind(role)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_site_member(
+        &self,
+        site_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        let result = sqlx::query("DELETE FROM site_members WHERE site_id = $1 AND user_id = $2")
+            .bind(site_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    pub(super) async fn check_property_site(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT 1 FROM properties WHERE id = $1 AND site_id = $2")
+            .bind(property_id)
+            .bind(site_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+        Ok(())
+    }
+
+    pub(super) async fn check_lease_site(
+        &self,
+        site_id: Uuid,
+        lease_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "SELECT 1 FROM leases l JOIN properties p ON p.id = l.property_id
+             WHERE l.id = $1 AND p.site_id = $2",
+        )
+        .bind(lease_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+        Ok(())
+    }
+
+    pub(super) async fn check_lead_site(
+        &self,
+        site_id: Uuid,
+        lead_id: Uuid,
+        customer_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "SELECT 1 FROM leads l JOIN customers c ON c.id = l.customer_id
+             WHERE l.id = $1 AND c.site_id = $2 AND l.customer_id = $3",
+        )
+        .bind(lead_id)
+        .bind(site_id)
+        .bind(customer_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+        Ok(())
+    }
+
+    pub(super) async fn check_customers_site(
+        &self,
+        site_id: Uuid,
+        customer_ids: &[Uuid],
+    ) -> Result<(), sqlx::Error> {
+        let missing: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM UNNEST($1::UUID[]) AS ids(id)
+             WHERE NOT EXISTS (SELECT 1 FROM customers c WHERE c.id = ids.id AND c.site_id = $2)",
+        )
+        .bind(customer_ids)
+        .bind(site_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        if missing > 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    pub async fn is_site_member(&self, site_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        Ok(self.site_role(site_id, user_id).await?.is_some())
+    }
+
+    pub async fn create_site(&self, body: &CreateSite) -> Result<Site, sqlx::Error> {
+        sqlx::query_as::<_, Site>(
+            "INSERT INTO sites (name, address, ada, parsel)
+             VALUES ($1, $2, $3, $4)
+             RETURNING *",
+        )
+        .bind(&body.name)
+        .bind(&body.address)
+        .bind(&body.ada)
+        .bind(&body.parsel)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn update_site(&self, site_id: Uuid, body: &CreateSite) -> Result<Site, sqlx::Error> {
+        sqlx::query_as::<_, Site>(
+            "UPDATE sites SET name = $2, address = $3, ada = $4, parsel = $5
+             WHERE id = $1
+             RETURNING *",
+        )
+        .bind(site_id)
+        .bind(&body.name)
+        .bind(&body.address)
+        .bind(&body.ada)
+        .bind(&body.parsel)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn create_block(
+        &self,
+        site_id: Uuid,
+        body: &CreateBlock,
+    ) -> Result<Block, sqlx::Error> {
+        sqlx::query_as::<_, Block>(
+            "INSERT INTO blocks (site_id, name, floor_count, has_elevator, address, ada, parsel)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING *",
+        )
+        .bind(site_id)
+        .bind(&body.name)
+        .bind(body.floor_count)
+        .bind(body.has_elevator)
+        .bind(&body.address)
+        .bind(&body.ada)
+        .bind(&body.parsel)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn update_block(
+        &self,
+        site_id: Uuid,
+        block_id: Uuid,
+        body: &CreateBlock,
+    ) -> Result<Block, sqlx::Error> {
+        sqlx::query_as::<_, Block>(
+            "UPDATE blocks SET
+                name = $2, floor_count = $3, has_elevator = $4,
+                address = $5, ada = $6, parsel = $7
+             WHERE id = $1 AND site_id = $8
+             RETURNING *",
+        )
+        .bind(block_id)
+        .bind(&body.name)
+        .bind(body.floor_count)
+        .bind(body.has_elevator)
+        .bind(&body.address)
+        .bind(&body.ada)
+        .bind(&body.parsel)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn delete_block(&self, site_id: Uuid, block_id: Uuid) -> Result<(), sqlx::Error> {
+        let result = sqlx::query("DELETE FROM blocks WHERE id = $1 AND site_id = $2")
+            .bind(block_id)
+            .bind(site_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    pub async fn site_tree(&self, site_id: Uuid) -> Result<SiteTree, sqlx::Error> {
+        let site = sqlx::query_as::<_, Site>("SELECT * FROM sites WHERE id = $1")
+            .bind(site_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(sqlx::Error::RowNotFound)?;
+
+        let blocks =
+            sqlx::query_as::<_, Block>("SELECT * FROM blocks WHERE site_id = $1 ORDER BY name")
+                .bind(site_id)
+                .fetch_all(&self.pool)
+                .await?;
+
+        let units = sqlx::query_as::<_, UnitSummary>(
+            "SELECT
+                p.id, p.block_id, p.daire_no, p.kat, p.kapi_no, p.oda_sayisi, p.kiraci_var_mi,
+                EXISTS (
+                    SELECT 1 FROM current_property_owners po WHERE po.property_id = p.id
+                ) AS sahip_var_mi
+             FROM properties p
+             JOIN blocks b ON b.id = p.block_id
+             WHERE b.site_id = $1
+             ORDER BY
+                p.block_id,
+                CASE WHEN p.kat ~ '^-?[0-9]+$' THEN p.kat::INTEGER END NULLS LAST,
+                p.kat,
+                p.kapi_no",
+        )
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut tree = SiteTree {
+            site,
+            occupancy: Occupancy::default(),
+            blocks: blocks
+                .into_iter()
+                .map(|block| BlockNode {
+                    block,
+                    occupancy: Occupancy::default(),
+                    floors: Vec::new(),
+                })
+                .collect(),
+        };
+
+        for unit in units {
+            let Some(block) = tree.blocks.iter_mut().find(|b| b.block.id == unit.block_id) else {
+                continue;
+            };
+
+            tree.occupancy.add(&unit);
+            block.occupancy.add(&unit);
+
+            let floor = match block.floors.last_mut() {
+                Some(floor) if floor.kat == unit.kat => floor,
+                _ => {
+                    block.floors.push(FloorNode {
+                        kat: unit.kat.clone(),
+                        occupancy: Occupancy::default(),
+                        units: Vec::new(),
+                    });
+                    block.floors.last_mut().unwrap()
+                }
+            };
+            floor.occupancy.add(&unit);
+            floor.units.push(unit);
+        }
+
+        Ok(tree)
+    }
+}
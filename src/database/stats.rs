@@ -0,0 +1,137 @@
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{CustomerStats, FacetCount, PortfolioStats, UnitStats},
+};
+
+impl Database {
+    pub async fn portfolio_stats(
+        &self,
+        site_id: Uuid,
+        not_contacted_days: i32,
+    ) -> Result<PortfolioStats, sqlx::Error> {
+        let (units, breakdown, customers, open_reminders) = tokio::try_join!(
+            self.unit_stats(site_id),
+            self.unit_breakdown(site_id),
+            self.customer_stats(site_id, not_contacted_days),
+            self.open_reminder_count(site_id),
+        )?;
+
+        let mut by_blok = Vec::new();
+        let mut by_daire_tipi = Vec::new();
+        let mut by_oda_sayisi = Vec::new();
+        for (dimension, value, count) in breakdown {
+            let target = match dimension.as_str() {
+                "blok" => &mut by_blok,
+                "daire_tipi" => &mut by_daire_tipi,
+                _ => &mut by_oda_sayisi,
+            };
+            target.push(FacetCount { value, count });
+        }
+
+        Ok(PortfolioStats {
+            units,
+            by_blok,
+            by_daire_tipi,
+            by_oda_sayisi,
+            customers,
+            open_reminders,
+            generated_at: chrono::Utc::now(),
+        })
+    }
+
+    async fn unit_stats(&self, site_id: Uuid) -> Result<UnitStats, sqlx::Error> {
+        sqlx::query_as::<_, UnitStats>(
+            "SELECT
+                COUNT(*) AS total_units,
+                COUNT(*) FILTER (WHERE o.property_id IS NOT NULL) AS owned_units,
+                COUNT(*) FILTER (WHERE o.property_id IS NULL) AS unowned_units,
+                COUNT(*) FILTER (WHERE p.kiraci_var_mi) AS tenant_occupied_units,
+                COALESCE(ROUND(AVG(p.kiraci_var_mi::INT), 4), 0)::FLOAT8 AS tenant_occupied_ratio,
+                COALESCE(SUM(p.brut_m2), 0) AS total_brut_m2,
+                ROUND(AVG(p.brut_m2), 2) AS avg_brut_m2,
+                COALESCE(SUM(p.net_m2), 0) AS total_net_m2,
+                ROUND(AVG(p.net_m2), 2) AS avg_net_m2
+             FROM properties p
+             LEFT JOIN (SELECT DISTINCT property_id FROM current_property_owners) o
+                ON o.property_id = p.id
+             WHERE p.site_id = $1",
+        )
+        .bind(site_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    // Blok, daire tipi ve oda sayısı kırılımları tek sorguda
+    async fn unit_breakdown(
+        &self,
+        site_id: Uuid,
+    ) -> Result<Vec<(String, Option<String>, i64)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT
+                CASE
+                    WHEN GROUPING(blok) = 0 THEN 'blok'
+                    WHEN GROUPING(daire_tipi) = 0 THEN 'daire_tipi'
+                    ELSE 'oda_sayisi'
+                END AS dimension,
+                COALESCE(blok, daire_tipi, oda_sayisi) AS value,
+                COUNT(*) AS count
+             FROM properties
+             WHERE site_id = $1
+             GROUP BY GROUPING SETS ((blok), (daire_tipi), (oda_sayisi))
+             ORDER BY 1, 2",
+        )
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn customer_stats(
+        &self,
+        site_id: Uuid,
+        not_contacted_days: i32,
+    ) -> Result<CustomerStats, sqlx::Error> {
+        let (total_customers, not_contacted): (i64, i64) = sqlx::query_as(
+            "SELECT
+                COUNT(*),
+                COUNT(*) FILTER (
+                    WHERE en_son_gorusuldu IS NULL
+                       OR en_son_gorusuldu < NOW() - make_interval(days => $2)
+                )
+             FROM customers
+             WHERE site_id = $1",
+        )
+        .bind(site_id)
+        .bind(not_contacted_days)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let by_uyruk = sqlx::query_as::<_, FacetCount>(
+            "SELECT uyruk AS value, COUNT(*) AS count
+             FROM customers
+             WHERE site_id = $1
+             GROUP BY 1
+             ORDER BY 2 DESC, 1",
+        )
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(CustomerStats {
+            total_customers,
+            by_uyruk,
+            not_contacted_days,
+            not_contacted,
+        })
+    }
+
+    async fn open_reminder_count(&self, site_id: Uuid) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM reminders WHERE site_id = $1 AND completed_at IS NULL",
+        )
+        .bind(site_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+}
@@ -0,0 +1,77 @@
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{CreateTenant, LeaseWithDetails, Tenant, TenantDetail},
+};
+
+impl Database {
+    pub async fn list_tenants(&self, site_id: Uuid) -> Result<Vec<Tenant>, sqlx::Error> {
+        sqlx::query_as::<_, Tenant>("SELECT * FROM tenants WHERE site_id = $1 ORDER BY ad_soyad")
+            .bind(site_id)
+            .fetch_all(&self.pool)
+            .await
+    }
+
+    pub async fn create_tenant(
+        &self,
+        site_id: Uuid,
+        body: &CreateTenant,
+    ) -> Result<Tenant, sqlx::Error> {
+        let mut conn = self.pool.acquire().await?;
+        insert_tenant(&mut conn, site_id, body).await
+    }
+
+    pub async fn tenant_detail(
+        &self,
+        site_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<TenantDetail, sqlx::Error> {
+        let tenant =
+            sqlx::query_as::<_, Tenant>("SELECT * FROM tenants WHERE id = $1 AND site_id = $2")
+                .bind(tenant_id)
+                .bind(site_id)
+                .fetch_optional(&self.pool)
+                .await?
+                .ok_or(sqlx::Error::RowNotFound)?;
+
+        let leases = sqlx::query_as::<_, LeaseWithDetails>(
+            "SELECT l.*, p.daire_no, t.ad_soyad AS kiraci_ad_soyad
+         FROM leases l
+         JOIN properties p ON p.id = l.property_id
+         JOIN tenants t ON t.id = l.tenant_id
+         WHERE l.tenant_id = $1
+         ORDER BY l.start_date DESC",
+        )
+        .bind(tenant_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(TenantDetail {
+            tenant_info: tenant,
+            tenant_leases: leases,
+        })
+    }
+}
+
+pub(super) async fn insert_tenant(
+    conn: &mut PgConnection,
+    site_id: Uuid,
+    body: &CreateTenant,
+) -> Result<Tenant, sqlx::Error> {
+    sqlx::query_as::<_, Tenant>(
+        "INSERT INTO tenants (ad_soyad, gsm, telefon, email, acil_kisi, uyruk, site_id)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)
+         RETURNING *",
+    )
+    .bind(&body.ad_soyad)
+    .bind(&body.gsm)
+    .bind(&body.telefon)
+    .bind(&body.email)
+    .bind(&body.acil_kisi)
+    .bind(&body.uyruk)
+    .bind(site_id)
+    .fetch_one(conn)
+    .await
+}
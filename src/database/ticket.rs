@@ -0,0 +1,272 @@
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::{
+    database::Database,
+    models::{
+        CreateTicket, CreateTicketComment, Page, Pagination, Ticket, TicketComment, TicketDetail,
+        TicketFilter, TicketPriority, TicketStatus, TicketWithDetails,
+    },
+};
+
+const TICKETS_WITH_DETAILS: &str = "SELECT t.*, p.daire_no,
+        c.ad_soyad AS reported_by_ad_soyad,
+        COALESCE(t.resolved_at, NOW()) > t.due_at AS sla_breached
+     FROM tickets t
+     JOIN properties p ON p.id = t.property_id
+     LEFT JOIN customers c ON c.id = t.reported_by";
+
+impl Database {
+    pub async fn list_tickets(
+        &self,
+        site_id: Uuid,
+        filter: &TicketFilter,
+        pagination: &Pagination,
+    ) -> Result<Page<TicketWithDetails>, sqlx::Error> {
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM tickets t");
+        push_ticket_filters(&mut count_query, site_id, filter);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut query = QueryBuilder::<Postgres>::new(TICKETS_WITH_DETAILS);
+        push_ticket_filters(&mut query, site_id, filter);
+        query
+            .push(" ORDER BY t.due_at, t.created_at LIMIT ")
+            .push_bind(pagination.per_page())
+            .push(" OFFSET ")
+            .push_bind(pagination.offset());
+
+        let items = query
+            .build_query_as::<TicketWithDetails>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(Page {
+            items,
+            total,
+            page: pagination.page(),
+            per_page: pagination.per_page(),
+        })
+    }
+
+    pub async fn list_customer_tickets(
+        &self,
+        site_id: Uuid,
+        customer_id: Uuid,
+    ) -> Result<Vec<TicketWithDetails>, sqlx::Error> {
+        sqlx::query_as::<_, TicketWithDetails>(&format!(
+            "{TICKETS_WITH_DETAILS}
+         WHERE t.reported_by = $1 AND p.site_id = $2
+         ORDER BY t.created_at DESC"
+        ))
+        .bind(customer_id)
+        .bind(site_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn list_property_tickets(
+        &self,
+        site_id: Uuid,
+        property_id: Uuid,
+    ) -> Result<Vec<TicketWithDetails>, sqlx::Error> {
+        self.check_property_site(site_id, property_id).await?;
+
+        sqlx::query_as::<_, TicketWithDetails>(&format!(
+            "{TICKETS_WITH_DETAILS}
+         WHERE t.property_id = $1
+         ORDER BY t.created_at DESC"
+        ))
+        .bind(property_id)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn ticket_detail(
+        &self,
+        site_id: Uuid,
+        ticket_id: Uuid,
+    ) -> Result<TicketDetail, sqlx::Error> {
+        let ticket = sqlx::query_as::<_, TicketWithDetails>(&format!(
+            "{TICKETS_WITH_DETAILS}
+         WHERE t.id = $1 AND p.site_id = $2"
+        ))
+        .bind(ticket_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+        let comments = sqlx::query_as::<_, TicketComment>(
+            "SELECT * FROM ticket_comments WHERE ticket_id = $1 ORDER BY created_at",
+        )
+        .bind(ticket_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(TicketDetail {
+            ticket_info: ticket,
+            ticket_comments: comments,
+        })
+    }
+
+    pub async fn get_ticket(&self, site_id: Uuid, ticket_id: Uuid) -> Result<Ticket, sqlx::Error> {
+        sqlx::query_as::<_, Ticket>(
+            "SELECT * FROM tickets
+             WHERE id = $1 AND property_id IN (SELECT id FROM properties WHERE site_id = $2)",
+        )
+        .bind(ticket_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn create_ticket(
+        &self,
+        site_id: Uuid,
+        created_by: Uuid,
+        body: &CreateTicket,
+    ) -> Result<Ticket, sqlx::Error> {
+        self.check_property_site(site_id, body.property_id).await?;
+        if let Some(reported_by) = body.reported_by {
+            self.check_customers_site(site_id, &[reported_by]).await?;
+        }
+
+        let priority = body.priority.unwrap_or(TicketPriority::Normal);
+        let due_at = chrono::Utc::now() + priority.sla();
+
+        sqlx::query_as::<_, Ticket>(
+            "INSERT INTO tickets
+                (property_id, reported_by, title, description, category, priority, due_at, created_by)
+         VALUES ($1, $2, $3, $4, COALESCE($5, 'other'), $6, $7, $8)
+         RETURNING *",
+        )
+        .bind(body.property_id)
+        .bind(body.reported_by)
+        .bind(&body.title)
+        .bind(&body.description)
+        .bind(body.category)
+        .bind(priority)
+        .bind(due_at)
+        .bind(created_by)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    pub async fn assign_ticket(
+        &self,
+        site_id: Uuid,
+        ticket_id: Uuid,
+        assigned_to: Uuid,
+    ) -> Result<Ticket, sqlx::Error> {
+        sqlx::query_as::<_, Ticket>(
+            "UPDATE tickets SET
+                assigned_to = $1,
+                assigned_at = NOW(),
+                status = CASE WHEN status = 'open' THEN 'assigned' ELSE status END
+         WHERE id = $2 AND property_id IN (SELECT id FROM properties WHERE site_id = $3)
+           AND status IN ('open', 'assigned', 'in_progress')
+         RETURNING *",
+        )
+        .bind(assigned_to)
+        .bind(ticket_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn update_ticket_status(
+        &self,
+        site_id: Uuid,
+        ticket_id: Uuid,
+        current: TicketStatus,
+        status: TicketStatus,
+        due_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Ticket, sqlx::Error> {
+        sqlx::query_as::<_, Ticket>(
+            "UPDATE tickets SET
+                status = $1,
+                started_at = CASE
+                    WHEN $1 = 'in_progress' THEN COALESCE(started_at, NOW())
+                    ELSE started_at
+                END,
+                resolved_at = CASE WHEN $1 = 'resolved' THEN NOW() ELSE NULL END,
+                assigned_to = CASE WHEN $1 = 'open' THEN NULL ELSE assigned_to END,
+                due_at = COALESCE($5, due_at)
+         WHERE id = $2 AND property_id IN (SELECT id FROM properties WHERE site_id = $3)
+           AND status = $4
+         RETURNING *",
+        )
+        .bind(status)
+        .bind(ticket_id)
+        .bind(site_id)
+        .bind(current)
+        .bind(due_at)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+
+    pub async fn create_ticket_comment(
+        &self,
+        site_id: Uuid,
+        ticket_id: Uuid,
+        user_id: Uuid,
+        body: &CreateTicketComment,
+    ) -> Result<TicketComment, sqlx::Error> {
+        sqlx::query_as::<_, TicketComment>(
+            "INSERT INTO ticket_comments (ticket_id, comment, created_by)
+         SELECT t.id, $2, $3
+         FROM tickets t
+         JOIN properties p ON p.id = t.property_id
+         WHERE t.id = $1 AND p.site_id = $4
+         RETURNING *",
+        )
+        .bind(ticket_id)
+        .bind(&body.comment)
+        .bind(user_id)
+        .bind(site_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)
+    }
+}
+
+fn push_ticket_filters(
+    query: &mut QueryBuilder<'_, Postgres>,
+    site_id: Uuid,
+    filter: &TicketFilter,
+) {
+    query
+        .push(" WHERE t.property_id IN (SELECT id FROM properties WHERE site_id = ")
+        .push_bind(site_id)
+        .push(")");
+
+    if let Some(property_id) = filter.property_id {
+        query.push(" AND t.property_id = ").push_bind(property_id);
+    }
+    if let Some(reported_by) = filter.reported_by {
+        query.push(" AND t.reported_by = ").push_bind(reported_by);
+    }
+    if let Some(assigned_to) = filter.assigned_to {
+        query.push(" AND t.assigned_to = ").push_bind(assigned_to);
+    }
+    if let Some(category) = filter.category {
+        query.push(" AND t.category = ").push_bind(category);
+    }
+    if let Some(priority) = filter.priority {
+        query.push(" AND t.priority = ").push_bind(priority);
+    }
+    if let Some(status) = filter.status {
+        query.push(" AND t.status = ").push_bind(status);
+    }
+    if let Some(sla_breached) = filter.sla_breached {
+        query
+            .push(" AND (COALESCE(t.resolved_at, NOW()) > t.due_at) = ")
+            .push_bind(sla_breached);
+    }
+}
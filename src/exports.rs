@@ -0,0 +1,211 @@
+use bigdecimal::{BigDecimal, ToPrimitive};
+use chrono::{DateTime, Utc};
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+use crate::models::{CustomerExportRow, ExportFormat, PropertyExportRow};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error(transparent)]
+    Xlsx(#[from] XlsxError),
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+// Hücre türü korunur: XLSX'te sayı ve tarih olarak, CSV'de Türkçe biçimde yazılır
+enum Cell {
+    Text(Option<String>),
+    Integer(i64),
+    Decimal(Option<BigDecimal>),
+    Date(Option<DateTime<Utc>>),
+    Bool(bool),
+}
+
+struct Column {
+    title: &'static str,
+    width: f64,
+}
+
+const fn column(title: &'static str, width: f64) -> Column {
+    Column { title, width }
+}
+
+const CUSTOMER_COLUMNS: [Column; 10] = [
+    column("Ad Soyad", 28.0),
+    column("GSM", 16.0),
+    column("Telefon", 16.0),
+    column("E-posta", 28.0),
+    column("Acil Durumda Ulaşılacak Kişi", 28.0),
+    column("Uyruk", 12.0),
+    column("Danışman", 24.0),
+    column("En Son Görüşülme", 18.0),
+    column("Daire Sayısı", 12.0),
+    column("Daireler", 28.0),
+];
+
+const PROPERTY_COLUMNS: [Column; 15] = [
+    column("Daire No", 12.0),
+    column("Blok", 8.0),
+    column("Kat", 8.0),
+    column("Kapı No", 8.0),
+    column("Daire Tipi", 12.0),
+    column("Oda Sayısı", 10.0),
+    column("Brüt m²", 10.0),
+    column("Net m²", 10.0),
+    column("Balkon m²", 10.0),
+    column("Cephe", 8.0),
+    column("Kiracı Var mı", 12.0),
+    column("Sahip Sayısı", 12.0),
+    column("Sahipler", 32.0),
+    column("Danışmanlar", 28.0),
+    column("En Son Görüşülme", 18.0),
+];
+
+pub fn customers(rows: &[CustomerExportRow], format: ExportFormat) -> Result<Vec<u8>, ExportError> {
+    let cells = rows
+        .iter()
+        .map(|c| {
+            vec![
+                Cell::Text(Some(c.ad_soyad.clone())),
+                Cell::Text(c.gsm.clone()),
+                Cell::Text(c.telefon.clone()),
+                Cell::Text(c.email.clone()),
+                Cell::Text(c.acil_kisi.clone()),
+                Cell::Text(c.uyruk.clone()),
+                Cell::Text(c.danisan.clone()),
+                Cell::Date(c.en_son_gorusuldu),
+                Cell::Integer(c.property_count),
+                Cell::Text(c.daire_nolar.clone()),
+            ]
+        })
+        .collect();
+
+    write(format, "Müşteriler", &CUSTOMER_COLUMNS, cells)
+}
+
+pub fn properties(
+    rows: &[PropertyExportRow],
+    format: ExportFormat,
+) -> Result<Vec<u8>, ExportError> {
+    let cells = rows
+        .iter()
+        .map(|p| {
+            vec![
+                Cell::Text(Some(p.daire_no.clone())),
+                Cell::Text(Some(p.blok.clone())),
+                Cell::Text(Some(p.kat.clone())),
+                Cell::Integer(p.kapi_no as i64),
+                Cell::Text(Some(p.daire_tipi.clone())),
+                Cell::Text(Some(p.oda_sayisi.clone())),
+                Cell::Decimal(Some(p.brut_m2.clone())),
+                Cell::Decimal(Some(p.net_m2.clone())),
+                Cell::Decimal(p.balkon_m2.clone()),
+                Cell::Text(p.cephe.clone()),
+                Cell::Bool(p.kiraci_var_mi),
+                Cell::Integer(p.sahip_sayisi),
+                Cell::Text(p.sahipler.clone()),
+                Cell::Text(p.danismanlar.clone()),
+                Cell::Date(p.en_son_gorusuldu),
+            ]
+        })
+        .collect();
+
+    write(format, "Daireler", &PROPERTY_COLUMNS, cells)
+}
+
+fn write(
+    format: ExportFormat,
+    sheet_name: &str,
+    columns: &[Column],
+    rows: Vec<Vec<Cell>>,
+) -> Result<Vec<u8>, ExportError> {
+    match format {
+        ExportFormat::Xlsx => write_xlsx(sheet_name, columns, rows),
+        ExportFormat::Csv => write_csv(columns, rows),
+    }
+}
+
+fn write_xlsx(
+    sheet_name: &str,
+    columns: &[Column],
+    rows: Vec<Vec<Cell>>,
+) -> Result<Vec<u8>, ExportError> {
+    let mut workbook = Workbook::new();
+    let bold = Format::new().set_bold();
+    let datetime_format = Format::new().set_num_format("dd.mm.yyyy hh:mm");
+    let decimal = Format::new().set_num_format("0.00");
+
+    let sheet = workbook.add_worksheet();
+    sheet.set_name(sheet_name)?;
+    for (col, c) in columns.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, c.title, &bold)?;
+        sheet.set_column_width(col as u16, c.width)?;
+    }
+
+    for (i, cells) in rows.into_iter().enumerate() {
+        let row = 1 + i as u32;
+        for (col, cell) in cells.into_iter().enumerate() {
+            let col = col as u16;
+            match cell {
+                Cell::Text(Some(value)) => {
+                    sheet.write_string(row, col, value)?;
+                }
+                Cell::Integer(value) => {
+                    sheet.write_number(row, col, value as f64)?;
+                }
+                Cell::Decimal(Some(value)) => {
+                    let value = value.to_f64().unwrap_or_default();
+                    sheet.write_number_with_format(row, col, value, &decimal)?;
+                }
+                Cell::Date(Some(value)) => {
+                    let local = crate::timezone::local(value);
+                    sheet.write_datetime_with_format(row, col, local, &datetime_format)?;
+                }
+                Cell::Bool(value) => {
+                    sheet.write_string(row, col, yes_no(value))?;
+                }
+                Cell::Text(None) | Cell::Decimal(None) | Cell::Date(None) => {}
+            }
+        }
+    }
+    sheet.set_freeze_panes(1, 1)?;
+    sheet.autofilter(0, 0, 0, columns.len() as u16 - 1)?;
+
+    Ok(workbook.save_to_buffer()?)
+}
+
+// Excel'in Türkçe ayarlarıyla doğrudan açılabilmesi için ";" ayırıcı, ondalık virgül ve BOM kullanılır
+fn write_csv(columns: &[Column], rows: Vec<Vec<Cell>>) -> Result<Vec<u8>, ExportError> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(b';')
+        .from_writer(b"\xEF\xBB\xBF".to_vec());
+
+    writer.write_record(columns.iter().map(|c| c.title))?;
+    for cells in rows {
+        writer.write_record(cells.into_iter().map(|cell| {
+            match cell {
+                Cell::Text(value) => value.unwrap_or_default(),
+                Cell::Integer(value) => value.to_string(),
+                Cell::Decimal(value) => value
+                    .map(|v| v.with_scale(2).to_string().replace('.', ","))
+                    .unwrap_or_default(),
+                Cell::Date(value) => value
+                    .map(|v| {
+                        crate::timezone::local(v)
+                            .format("%d.%m.%Y %H:%M")
+                            .to_string()
+                    })
+                    .unwrap_or_default(),
+                Cell::Bool(value) => yes_no(value).to_string(),
+            }
+        }))?;
+    }
+
+    writer.into_inner().map_err(|e| e.into_error().into())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "Evet" } else { "Hayır" }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    AppState,
+    models::{
+        AgendaDay, AgendaQuery, AgendaRange, Appointment, AppointmentFilter,
+        AppointmentWithDetails, Claims, CreateAppointment, SiteContext, UpdateAppointment,
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::Datelike;
+use std::str::FromStr;
+use uuid::Uuid;
+
+const EXCLUSION_VIOLATION: &str = "23P01";
+
+fn appointment_error(e: sqlx::Error) -> StatusCode {
+    match e {
+        sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+        sqlx::Error::Database(e) if e.code().as_deref() == Some(EXCLUSION_VIOLATION) => {
+            StatusCode::CONFLICT
+        }
+        sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::BAD_REQUEST,
+        sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+pub async fn list_appointments(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(filter): Query<AppointmentFilter>,
+) -> Result<Json<Vec<AppointmentWithDetails>>, StatusCode> {
+    let result = state
+        .db
+        .list_appointments(site.site_id, &filter)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn agenda(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Query(query): Query<AgendaQuery>,
+) -> Result<Json<Vec<AgendaDay>>, StatusCode> {
+    let danisan_id = match query.danisan_id {
+        Some(id) => id,
+        None => Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?,
+    };
+    let date = query.date.unwrap_or_else(crate::timezone::today);
+
+    let (from, days) = match query.range.unwrap_or(AgendaRange::Day) {
+        AgendaRange::Day => (date, 1),
+        AgendaRange::Week => (
+            date - chrono::Days::new(date.weekday().num_days_from_monday() as u64),
+            7,
+        ),
+    };
+
+    let result = state
+        .db
+        .consultant_agenda(site.site_id, danisan_id, from, days)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn create_appointment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Json(body): Json<CreateAppointment>,
+) -> Result<(StatusCode, Json<Appointment>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let danisan_id = body.danisan_id.unwrap_or(user_id);
+    crate::handlers::customers::ensure_site_member(&state, &site, danisan_id).await?;
+    if let Some(lead_id) = body.lead_id {
+        let lead = state
+            .db
+            .get_lead(site.site_id, lead_id)
+            .await
+            .map_err(appointment_error)?;
+        // Randevu, talebin sahibi olan müşteriyle yapılır
+        if lead.lead.customer_id != body.customer_id {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+    }
+
+    let appointment = state
+        .db
+        .create_appointment(site.site_id, danisan_id, user_id, &body)
+        .await
+        .map_err(appointment_error)?;
+
+    Ok((StatusCode::CREATED, Json(appointment)))
+}
+
+pub async fn update_appointment(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(appointment_id): Path<Uuid>,
+    Json(body): Json<UpdateAppointment>,
+) -> Result<Json<Appointment>, StatusCode> {
+    let appointment = state
+        .db
+        .update_appointment(site.site_id, appointment_id, &body)
+        .await
+        .map_err(appointment_error)?;
+
+    Ok(Json(appointment))
+}
@@ -1,9 +1,16 @@
 use crate::AppState;
-use crate::models::{Claims, CreateUser, LoginRequest, LoginResponse, User};
+use crate::models::{Claims, LoginRequest, LoginResponse, SiteContext, SiteRole};
 use axum::{Json, extract::State, http::StatusCode};
-use axum::{extract::Request, http::header, middleware::Next, response::Response};
+use axum::{
+    extract::Request,
+    http::{Method, header},
+    middleware::Next,
+    response::Response,
+};
 use jsonwebtoken::{DecodingKey, Validation, decode};
 use jsonwebtoken::{EncodingKey, Header, encode};
+use std::str::FromStr;
+use uuid::Uuid;
 
 pub async fn login(
     State(state): State<AppState>,
@@ -70,3 +77,53 @@ pub async fn auth_middleware(
 
     Ok(next.run(req).await)
 }
+
+pub const SITE_HEADER: &str = "x-site-id";
+
+// auth_middleware'den sonra çalışır; isteğin hangi sitede yapıldığını belirler
+pub async fn site_middleware(
+    State(state): State<AppState>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let claims = req
+        .extensions()
+        .get::<Claims>()
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let site_id = match req.headers().get(SITE_HEADER) {
+        Some(value) => value
+            .to_str()
+            .ok()
+            .and_then(|v| Uuid::from_str(v.trim()).ok())
+            .ok_or(StatusCode::BAD_REQUEST)?,
+        None => {
+            // Tek sitesi olan kullanıcı başlık göndermek zorunda değil
+            let sites = state
+                .db
+                .list_user_sites(user_id)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            match sites.as_slice() {
+                [only] => only.site.id,
+                _ => return Err(StatusCode::BAD_REQUEST),
+            }
+        }
+    };
+
+    let role = state
+        .db
+        .site_role(site_id, user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::FORBIDDEN)?;
+
+    if role == SiteRole::Viewer && !matches!(*req.method(), Method::GET | Method::HEAD) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    req.extensions_mut().insert(SiteContext { site_id, role });
+
+    Ok(next.run(req).await)
+}
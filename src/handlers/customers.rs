@@ -4,49 +4,80 @@ use crate::{
     AppState,
     models::{
         Claims, CreateCustomer, CreateCustomerNote, Customer, CustomerDetail, CustomerNote,
-        CustomerWithProperties,
+        CustomerWithProperties, ExportQuery, SiteContext,
     },
 };
 use axum::{
     Extension, Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
+    response::IntoResponse,
 };
 use serde::Deserialize;
 use uuid::Uuid;
 
 pub async fn list_customers(
     State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
 ) -> Result<Json<Vec<CustomerWithProperties>>, StatusCode> {
     let result = state
         .db
-        .list_customers()
+        .list_customers(site.site_id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     Ok(Json(result))
 }
 
+pub async fn export_customers(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(query): Query<ExportQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    if !site.is_manager() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let rows = state
+        .db
+        .export_customers(site.site_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let data = crate::exports::customers(&rows, query.format)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(super::export_download(query.format, "musteriler", data))
+}
+
 pub async fn customer_detail(
     State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
     Path(customer_id): Path<Uuid>,
 ) -> Result<Json<CustomerDetail>, StatusCode> {
     let customer = state
         .db
-        .customer_detail(customer_id)
+        .customer_detail(site.site_id, customer_id)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
 
     Ok(Json(customer))
 }
 
 pub async fn create_customer(
     State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
     Json(body): Json<CreateCustomer>,
 ) -> Result<(StatusCode, Json<Customer>), StatusCode> {
+    if let Some(danisan_id) = body.danisan_id {
+        ensure_site_member(&state, &site, danisan_id).await?;
+    }
+
     let customer = state
         .db
-        .create_customer(&body)
+        .create_customer(site.site_id, &body)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
@@ -60,12 +91,15 @@ pub struct AssignDanisan {
 
 pub async fn assign_consultant(
     State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
     Path(customer_id): Path<Uuid>,
     Json(body): Json<AssignDanisan>,
 ) -> Result<Json<Customer>, StatusCode> {
+    ensure_site_member(&state, &site, body.danisan_id).await?;
+
     let customer = state
         .db
-        .assign_consultant(customer_id, body.danisan_id)
+        .assign_consultant(site.site_id, customer_id, body.danisan_id)
         .await
         .map_err(|e| match e {
             sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
@@ -78,25 +112,35 @@ pub async fn assign_consultant(
 pub async fn create_customer_note(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
     Path(customer_id): Path<Uuid>,
     Json(body): Json<CreateCustomerNote>,
 ) -> Result<(StatusCode, Json<CustomerNote>), StatusCode> {
     let note = state
         .db
-        .create_customer_note(customer_id, Uuid::from_str(&claims.sub).unwrap(), &body)
+        .create_customer_note(
+            site.site_id,
+            customer_id,
+            Uuid::from_str(&claims.sub).unwrap(),
+            &body,
+        )
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
 
     Ok((StatusCode::CREATED, Json(note)))
 }
 
 pub async fn delete_customer_note(
     State(state): State<AppState>,
-    Path((_customer_id, note_id)): Path<(Uuid, Uuid)>,
+    Extension(site): Extension<SiteContext>,
+    Path((customer_id, note_id)): Path<(Uuid, Uuid)>,
 ) -> Result<StatusCode, StatusCode> {
     state
         .db
-        .delete_customer_note(note_id)
+        .delete_customer_note(site.site_id, customer_id, note_id)
         .await
         .map_err(|e| match e {
             sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
@@ -108,12 +152,12 @@ pub async fn delete_customer_note(
 
 pub async fn update_last_contact(
     State(state): State<AppState>,
-    Extension(_claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
     Path(customer_id): Path<Uuid>,
 ) -> Result<Json<Customer>, StatusCode> {
     let customer = state
         .db
-        .update_last_contact(customer_id)
+        .update_last_contact(site.site_id, customer_id)
         .await
         .map_err(|e| match e {
             sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
@@ -122,3 +166,21 @@ pub async fn update_last_contact(
 
     Ok(Json(customer))
 }
+
+pub(crate) async fn ensure_site_member(
+    state: &AppState,
+    site: &SiteContext,
+    user_id: Uuid,
+) -> Result<(), StatusCode> {
+    let is_member = state
+        .db
+        .is_site_member(site.site_id, user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if !is_member {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    Ok(())
+}
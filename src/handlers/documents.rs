@@ -0,0 +1,288 @@
+use std::str::FromStr;
+
+use axum::{
+    Extension, Json,
+    extract::{Multipart, Path, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{
+        ALLOWED_DOCUMENT_TYPES, Claims, Document, DocumentCategory, DocumentOwner, NewDocument,
+        SiteContext,
+    },
+    storage::StorageError,
+};
+
+pub fn max_document_bytes() -> usize {
+    std::env::var("DOCUMENT_MAX_BYTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(20 * 1024 * 1024)
+}
+
+pub async fn upload_customer_document(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(customer_id): Path<Uuid>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<Document>), StatusCode> {
+    upload_document(
+        &state,
+        &claims,
+        site,
+        DocumentOwner::Customer(customer_id),
+        multipart,
+    )
+    .await
+}
+
+pub async fn upload_property_document(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<Document>), StatusCode> {
+    upload_document(
+        &state,
+        &claims,
+        site,
+        DocumentOwner::Property(property_id),
+        multipart,
+    )
+    .await
+}
+
+pub async fn upload_lease_document(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(lease_id): Path<Uuid>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<Document>), StatusCode> {
+    upload_document(
+        &state,
+        &claims,
+        site,
+        DocumentOwner::Lease(lease_id),
+        multipart,
+    )
+    .await
+}
+
+pub async fn list_customer_documents(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(customer_id): Path<Uuid>,
+) -> Result<Json<Vec<Document>>, StatusCode> {
+    list_documents(&state, site, DocumentOwner::Customer(customer_id)).await
+}
+
+pub async fn list_property_documents(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+) -> Result<Json<Vec<Document>>, StatusCode> {
+    list_documents(&state, site, DocumentOwner::Property(property_id)).await
+}
+
+pub async fn list_lease_documents(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(lease_id): Path<Uuid>,
+) -> Result<Json<Vec<Document>>, StatusCode> {
+    list_documents(&state, site, DocumentOwner::Lease(lease_id)).await
+}
+
+pub async fn download_document(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(document_id): Path<Uuid>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let document = state
+        .db
+        .get_document(site.site_id, document_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    let data = state
+        .storage
+        .get(&document.storage_key)
+        .await
+        .map_err(|e| match e {
+            StorageError::NotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, document.content_type.clone()),
+            (
+                header::CONTENT_DISPOSITION,
+                content_disposition(&document.file_name),
+            ),
+        ],
+        data,
+    ))
+}
+
+pub async fn delete_document(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(document_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let document = state
+        .db
+        .get_document(site.site_id, document_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    if !site.is_manager() && document.uploaded_by.map(|id| id.to_string()) != Some(claims.sub) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    state
+        .db
+        .delete_document(site.site_id, document_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    match state.storage.delete(&document.storage_key).await {
+        Ok(()) | Err(StorageError::NotFound) => {}
+        Err(e) => eprintln!("Dosya silinemedi ({}): {}", document.storage_key, e),
+    }
+
+    Ok(StatusCode::OK)
+}
+
+async fn list_documents(
+    state: &AppState,
+    site: SiteContext,
+    owner: DocumentOwner,
+) -> Result<Json<Vec<Document>>, StatusCode> {
+    let documents = state
+        .db
+        .list_documents(site.site_id, owner)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(documents))
+}
+
+async fn upload_document(
+    state: &AppState,
+    claims: &Claims,
+    site: SiteContext,
+    owner: DocumentOwner,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<Document>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let mut file: Option<(String, Vec<u8>)> = None;
+    let mut category = DocumentCategory::Diger;
+
+    while let Some(field) = multipart.next_field().await.map_err(|e| e.status())? {
+        match field.name() {
+            Some("file") => {
+                let file_name = field
+                    .file_name()
+                    .map(sanitize_file_name)
+                    .filter(|n| !n.is_empty())
+                    .unwrap_or_else(|| "dosya".to_string());
+                let data = field.bytes().await.map_err(|e| e.status())?;
+                file = Some((file_name, data.to_vec()));
+            }
+            Some("category") => {
+                let value = field.text().await.map_err(|e| e.status())?;
+                category = serde_json::from_value(serde_json::Value::String(value))
+                    .map_err(|_| StatusCode::BAD_REQUEST)?;
+            }
+            _ => {}
+        }
+    }
+
+    let (file_name, data) = file.ok_or(StatusCode::BAD_REQUEST)?;
+    if data.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    if data.len() > max_document_bytes() {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    let content_type = infer::get(&data)
+        .map(|t| t.mime_type())
+        .filter(|m| ALLOWED_DOCUMENT_TYPES.contains(m))
+        .ok_or(StatusCode::UNSUPPORTED_MEDIA_TYPE)?;
+
+    let storage_key = format!("documents/{}", Uuid::new_v4());
+    let new_document = NewDocument {
+        owner,
+        category,
+        file_name,
+        content_type: content_type.to_string(),
+        size_bytes: data.len() as i64,
+        checksum: format!("{:x}", Sha256::digest(&data)),
+        storage_key,
+        uploaded_by: user_id,
+    };
+
+    state
+        .storage
+        .put(&new_document.storage_key, &data)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    match state.db.create_document(site.site_id, &new_document).await {
+        Ok(document) => Ok((StatusCode::CREATED, Json(document))),
+        Err(e) => {
+            let _ = state.storage.delete(&new_document.storage_key).await;
+            Err(match e {
+                sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+                sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            })
+        }
+    }
+}
+
+pub(crate) fn sanitize_file_name(name: &str) -> String {
+    let name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    name.chars()
+        .filter(|c| !c.is_control() && *c != '"')
+        .collect()
+}
+
+fn content_disposition(file_name: &str) -> String {
+    let ascii: String = file_name
+        .chars()
+        .map(|c| if c.is_ascii() { c } else { '_' })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+
+    format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        ascii, encoded
+    )
+}
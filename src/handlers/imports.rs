@@ -0,0 +1,170 @@
+use std::str::FromStr;
+
+use axum::{
+    Extension, Json,
+    extract::{Multipart, Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    commands::{
+        ImportKind, ImportMode, ImportOptions,
+        import_run::ImportFile,
+        sheet::{self, SheetOptions},
+    },
+    handlers::documents::sanitize_file_name,
+    jobs,
+    models::{Claims, ImportStatus, SiteContext},
+};
+
+pub fn max_import_upload_bytes() -> usize {
+    std::env::var("IMPORT_MAX_UPLOAD_BYTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(20 * 1024 * 1024)
+}
+
+fn parse_flag(value: &str) -> bool {
+    matches!(value.trim(), "true" | "1" | "on")
+}
+
+pub async fn create_import(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<ImportStatus>), StatusCode> {
+    if !site.is_manager() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let mut file = None;
+    let mut kind = None;
+    let mut options = ImportOptions {
+        dry_run: false,
+        report_path: None,
+        mode: ImportMode::AllOrNothing,
+        force: false,
+        user_email: None,
+        columns_path: None,
+        source: SheetOptions::default(),
+        sync: false,
+        unlink_missing: false,
+    };
+
+    while let Some(field) = multipart.next_field().await.map_err(|e| e.status())? {
+        let name = field.name().unwrap_or_default().to_string();
+        if name == "file" {
+            let file_name = field
+                .file_name()
+                .map(sanitize_file_name)
+                .filter(|n| !n.is_empty())
+                .unwrap_or_default();
+            let data = field.bytes().await.map_err(|e| e.status())?;
+            file = Some(ImportFile {
+                name: file_name,
+                data: data.to_vec(),
+            });
+            continue;
+        }
+
+        let value = field.text().await.map_err(|e| e.status())?;
+        match name.as_str() {
+            "kind" => kind = Some(ImportKind::parse(&value).ok_or(StatusCode::BAD_REQUEST)?),
+            "mode" => options.mode = ImportMode::parse(&value).ok_or(StatusCode::BAD_REQUEST)?,
+            "dry_run" => options.dry_run = parse_flag(&value),
+            "force" => options.force = parse_flag(&value),
+            "sync" => options.sync = parse_flag(&value),
+            "unlink_missing" => options.unlink_missing = parse_flag(&value),
+            "sheet" => options.source.sheet = Some(value),
+            "delimiter" => options.source.delimiter = Some(value),
+            "encoding" => options.source.encoding = Some(value),
+            "decimal" => {
+                options.source.decimal_comma = match value.as_str() {
+                    "comma" => Some(true),
+                    "point" => Some(false),
+                    _ => return Err(StatusCode::BAD_REQUEST),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (Some(file), Some(kind)) = (file, kind) else {
+        return Err(StatusCode::BAD_REQUEST);
+    };
+    options.sync |= options.unlink_missing;
+    if !sheet::is_supported(&file.name) {
+        return Err(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    let status = jobs::imports::spawn(
+        state.db.clone(),
+        state.import_jobs.clone(),
+        site.site_id,
+        user_id,
+        kind,
+        file,
+        options,
+    );
+
+    Ok((StatusCode::ACCEPTED, Json(status)))
+}
+
+pub async fn get_import(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ImportStatus>, StatusCode> {
+    if let Some(status) = state.import_jobs.get(site.site_id, id) {
+        return Ok(Json(status));
+    }
+
+    let run = state
+        .db
+        .get_import_run(site.site_id, id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+    let errors = state
+        .db
+        .list_import_run_errors(run.id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let unmatched_tenants = state
+        .db
+        .list_import_run_unmatched_tenants(run.id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let missing = state
+        .db
+        .list_import_run_missing_records(run.id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let total = run.created_count + run.updated_count + run.skipped_count + run.error_count;
+    Ok(Json(ImportStatus {
+        id: run.id,
+        kind: run.kind,
+        file_name: run.file_name,
+        status: run.status,
+        dry_run: false,
+        sync: run.sync,
+        total_rows: total,
+        processed_rows: total,
+        created_count: run.created_count,
+        updated_count: run.updated_count,
+        skipped_count: run.skipped_count,
+        error_count: run.error_count,
+        errors,
+        unmatched_tenants,
+        missing_count: run.missing_count,
+        missing,
+        message: None,
+    }))
+}
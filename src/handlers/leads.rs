@@ -0,0 +1,259 @@
+use crate::{
+    AppState,
+    jobs::listing_matches::spawn_for_lead,
+    matching::score_listing,
+    models::{
+        Claims, CreateLead, CreateLeadStage, Lead, LeadFilter, LeadStage, LeadWithDetails,
+        ListingMatch, MatchQuery, MoveLeadStage, Page, Pagination, PipelineColumn, SiteContext,
+        UpdateLead,
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub async fn list_lead_stages(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<LeadStage>>, StatusCode> {
+    let result = state
+        .db
+        .list_lead_stages()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn create_lead_stage(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(body): Json<CreateLeadStage>,
+) -> Result<(StatusCode, Json<LeadStage>), StatusCode> {
+    if !claims.is_admin {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let stage = state
+        .db
+        .create_lead_stage(&body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_unique_violation() => StatusCode::CONFLICT,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(stage)))
+}
+
+pub async fn update_lead_stage(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(stage_id): Path<Uuid>,
+    Json(body): Json<CreateLeadStage>,
+) -> Result<Json<LeadStage>, StatusCode> {
+    if !claims.is_admin {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let stage = state
+        .db
+        .update_lead_stage(stage_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_unique_violation() => StatusCode::CONFLICT,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(stage))
+}
+
+pub async fn delete_lead_stage(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(stage_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    if !claims.is_admin {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    state
+        .db
+        .delete_lead_stage(stage_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(StatusCode::OK)
+}
+
+pub async fn list_leads(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(filter): Query<LeadFilter>,
+    Query(pagination): Query<Pagination>,
+) -> Result<Json<Page<LeadWithDetails>>, StatusCode> {
+    let result = state
+        .db
+        .list_leads(site.site_id, &filter, &pagination)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn lead_pipeline(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(filter): Query<LeadFilter>,
+) -> Result<Json<Vec<PipelineColumn>>, StatusCode> {
+    let result = state
+        .db
+        .lead_pipeline(site.site_id, &filter)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn lead_detail(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(lead_id): Path<Uuid>,
+) -> Result<Json<LeadWithDetails>, StatusCode> {
+    let lead = state
+        .db
+        .get_lead(site.site_id, lead_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(lead))
+}
+
+pub async fn create_lead(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Json(body): Json<CreateLead>,
+) -> Result<(StatusCode, Json<Lead>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    if body.customer_id.is_none() && body.prospect.is_none() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let prospect_danisan_id = body.prospect.as_ref().and_then(|p| p.danisan_id);
+    for danisan_id in [body.danisan_id, prospect_danisan_id].into_iter().flatten() {
+        crate::handlers::customers::ensure_site_member(&state, &site, danisan_id).await?;
+    }
+
+    let lead = state
+        .db
+        .create_lead(site.site_id, user_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+    spawn_for_lead(state.db.clone(), site.site_id, lead.id);
+
+    Ok((StatusCode::CREATED, Json(lead)))
+}
+
+pub async fn update_lead(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(lead_id): Path<Uuid>,
+    Json(body): Json<UpdateLead>,
+) -> Result<Json<Lead>, StatusCode> {
+    if let Some(danisan_id) = body.danisan_id {
+        crate::handlers::customers::ensure_site_member(&state, &site, danisan_id).await?;
+    }
+
+    let lead = state
+        .db
+        .update_lead(site.site_id, lead_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::BAD_REQUEST,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+    spawn_for_lead(state.db.clone(), site.site_id, lead.id);
+
+    Ok(Json(lead))
+}
+
+pub async fn move_lead_stage(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(lead_id): Path<Uuid>,
+    Json(body): Json<MoveLeadStage>,
+) -> Result<Json<Lead>, StatusCode> {
+    let lead = state
+        .db
+        .move_lead_stage(site.site_id, lead_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+    // Yeniden açılan müşteri isteği de eşleştirilir; kapalı istekler atlanır
+    spawn_for_lead(state.db.clone(), site.site_id, lead.id);
+
+    Ok(Json(lead))
+}
+
+pub async fn lead_matches(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(lead_id): Path<Uuid>,
+    Query(query): Query<MatchQuery>,
+) -> Result<Json<Vec<ListingMatch>>, StatusCode> {
+    let lead = state
+        .db
+        .get_lead(site.site_id, lead_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    let listings = state
+        .db
+        .list_active_listings(site.site_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let min_score = query.min_score.unwrap_or(1);
+    let mut matches: Vec<ListingMatch> = listings
+        .into_iter()
+        .filter_map(|listing| {
+            let m = score_listing(&lead.lead, &listing)?;
+            (m.score >= min_score).then_some(ListingMatch {
+                listing,
+                score: m.score,
+                reasons: m.reasons,
+            })
+        })
+        .collect();
+
+    matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+    matches.truncate(query.limit.unwrap_or(20));
+
+    Ok(Json(matches))
+}
@@ -0,0 +1,84 @@
+use crate::{
+    AppState,
+    models::{
+        CreateLease, ExpiringLeasesQuery, Lease, LeaseWithDetails, SiteContext, UpdateLeaseStatus,
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+pub async fn list_property_leases(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+) -> Result<Json<Vec<LeaseWithDetails>>, StatusCode> {
+    let result = state
+        .db
+        .list_property_leases(site.site_id, property_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(result))
+}
+
+pub async fn create_lease(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    Json(body): Json<CreateLease>,
+) -> Result<(StatusCode, Json<Lease>), StatusCode> {
+    let lease = state
+        .db
+        .create_lease(site.site_id, property_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_unique_violation() => StatusCode::CONFLICT,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(lease)))
+}
+
+pub async fn update_lease_status(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(lease_id): Path<Uuid>,
+    Json(body): Json<UpdateLeaseStatus>,
+) -> Result<Json<Lease>, StatusCode> {
+    let lease = state
+        .db
+        .update_lease_status(site.site_id, lease_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_unique_violation() => StatusCode::CONFLICT,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(lease))
+}
+
+pub async fn list_expiring_leases(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(query): Query<ExpiringLeasesQuery>,
+) -> Result<Json<Vec<LeaseWithDetails>>, StatusCode> {
+    let result = state
+        .db
+        .list_expiring_leases(site.site_id, query.days.unwrap_or(30))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
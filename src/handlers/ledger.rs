@@ -0,0 +1,169 @@
+use crate::{
+    AppState,
+    models::{
+        Claims, CreateCharge, CreatePayment, GenerateAidat, GenerateAidatResult, LedgerCharge,
+        LedgerChargeWithBalance, LedgerPayment, PropertyLedger, SiteContext, StatementEntry,
+        StatementQuery,
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use bigdecimal::BigDecimal;
+use chrono::{Datelike, Months};
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub async fn property_ledger(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+) -> Result<Json<PropertyLedger>, StatusCode> {
+    let ledger = state
+        .db
+        .property_ledger(site.site_id, property_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(ledger))
+}
+
+pub async fn create_charge(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    Json(body): Json<CreateCharge>,
+) -> Result<(StatusCode, Json<LedgerCharge>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let charge = state
+        .db
+        .create_ledger_charge(site.site_id, property_id, user_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_unique_violation() => StatusCode::CONFLICT,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(charge)))
+}
+
+pub async fn create_payment(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    Json(body): Json<CreatePayment>,
+) -> Result<(StatusCode, Json<LedgerPayment>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    if let Some(charge_id) = body.charge_id {
+        let charge = state
+            .db
+            .get_ledger_charge(site.site_id, charge_id)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            })?;
+
+        if charge.property_id != property_id
+            || body
+                .currency
+                .as_ref()
+                .is_some_and(|c| *c != charge.currency)
+        {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+    }
+
+    let payment = state
+        .db
+        .create_ledger_payment(site.site_id, property_id, user_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(payment)))
+}
+
+pub async fn generate_aidat(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Json(body): Json<GenerateAidat>,
+) -> Result<(StatusCode, Json<GenerateAidatResult>), StatusCode> {
+    if !site.is_manager() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let zero = BigDecimal::from(0);
+    if body.rate_per_m2 <= zero {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let period = body.period.with_day(1).ok_or(StatusCode::BAD_REQUEST)?;
+    let due_date = match body.due_date {
+        Some(d) => d,
+        None => period
+            .checked_add_months(Months::new(1))
+            .and_then(|d| d.pred_opt())
+            .ok_or(StatusCode::BAD_REQUEST)?,
+    };
+
+    let result = state
+        .db
+        .generate_aidat(site.site_id, user_id, period, due_date, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+pub async fn list_overdue_charges(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+) -> Result<Json<Vec<LedgerChargeWithBalance>>, StatusCode> {
+    let result = state
+        .db
+        .list_overdue_charges(site.site_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn customer_statement(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(customer_id): Path<Uuid>,
+    Query(query): Query<StatementQuery>,
+) -> Result<Json<Vec<StatementEntry>>, StatusCode> {
+    let result = state
+        .db
+        .customer_statement(site.site_id, customer_id, query.from, query.to)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(result))
+}
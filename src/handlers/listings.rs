@@ -0,0 +1,117 @@
+use crate::{
+    AppState,
+    models::{
+        Claims, CreateListing, Listing, ListingFilter, ListingWithProperty, Page, Pagination,
+        SiteContext, UpdateListing,
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub async fn list_listings(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(filter): Query<ListingFilter>,
+    Query(pagination): Query<Pagination>,
+) -> Result<Json<Page<ListingWithProperty>>, StatusCode> {
+    let result = state
+        .db
+        .list_listings(site.site_id, &filter, &pagination)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn list_property_listings(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    Query(pagination): Query<Pagination>,
+) -> Result<Json<Page<ListingWithProperty>>, StatusCode> {
+    let filter = ListingFilter {
+        property_id: Some(property_id),
+        ..Default::default()
+    };
+
+    let result = state
+        .db
+        .list_listings(site.site_id, &filter, &pagination)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn listing_detail(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(listing_id): Path<Uuid>,
+) -> Result<Json<ListingWithProperty>, StatusCode> {
+    let listing = state
+        .db
+        .get_listing(site.site_id, listing_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(listing))
+}
+
+pub async fn create_listing(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Json(body): Json<CreateListing>,
+) -> Result<(StatusCode, Json<Listing>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+    if let Some(danisan_id) = body.danisan_id {
+        crate::handlers::customers::ensure_site_member(&state, &site, danisan_id).await?;
+    }
+
+    let listing = state
+        .db
+        .create_listing(site.site_id, user_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_unique_violation() => StatusCode::CONFLICT,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(listing)))
+}
+
+pub async fn update_listing(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(listing_id): Path<Uuid>,
+    Json(body): Json<UpdateListing>,
+) -> Result<Json<Listing>, StatusCode> {
+    if let Some(danisan_id) = body.danisan_id {
+        crate::handlers::customers::ensure_site_member(&state, &site, danisan_id).await?;
+    }
+
+    let listing = state
+        .db
+        .update_listing(site.site_id, listing_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_unique_violation() => StatusCode::CONFLICT,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::BAD_REQUEST,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(listing))
+}
@@ -1,5 +1,44 @@
+pub mod appointments;
 pub mod auth;
 pub mod customers;
+pub mod documents;
+pub mod imports;
+pub mod leads;
+pub mod leases;
+pub mod ledger;
+pub mod listings;
+pub mod notifications;
+pub mod photos;
 pub mod properties;
 pub mod reminders;
+pub mod reports;
+pub mod sites;
+pub mod stats;
+pub mod tenants;
+pub mod tickets;
 pub mod users;
+
+use axum::{http::header, response::IntoResponse};
+
+use crate::models::ExportFormat;
+
+// Dışa aktarılan dosyayı indirme olarak döner; dosya adına tarih eklenir
+fn export_download(format: ExportFormat, name: &str, data: Vec<u8>) -> impl IntoResponse {
+    let file_name = format!(
+        "{}-{}.{}",
+        name,
+        crate::timezone::today(),
+        format.extension()
+    );
+
+    (
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", file_name),
+            ),
+        ],
+        data,
+    )
+}
@@ -0,0 +1,47 @@
+use crate::{
+    AppState,
+    models::{Claims, Notification, NotificationQuery, SiteContext},
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub async fn list_notifications(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Query(query): Query<NotificationQuery>,
+) -> Result<Json<Vec<Notification>>, StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let result = state
+        .db
+        .list_notifications(site.site_id, user_id, query.unread_only)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn mark_notification_read(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(notification_id): Path<Uuid>,
+) -> Result<Json<Notification>, StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let notification = state
+        .db
+        .mark_notification_read(notification_id, user_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(notification))
+}
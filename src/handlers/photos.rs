@@ -0,0 +1,254 @@
+use std::str::FromStr;
+
+use axum::{
+    Extension, Json,
+    extract::{Multipart, Path, Query, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    handlers::documents::sanitize_file_name,
+    models::{Claims, NewPropertyPhoto, PhotoFileQuery, PropertyPhoto, ReorderPhotos, SiteContext},
+    photos::{self, PhotoError},
+    storage::StorageError,
+};
+
+pub fn max_photo_upload_bytes() -> usize {
+    std::env::var("PHOTO_MAX_UPLOAD_BYTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(50 * 1024 * 1024)
+}
+
+pub async fn list_property_photos(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+) -> Result<Json<Vec<PropertyPhoto>>, StatusCode> {
+    let photos = state
+        .db
+        .list_property_photos(site.site_id, property_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(photos))
+}
+
+pub async fn upload_property_photos(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<Vec<PropertyPhoto>>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let mut files = Vec::new();
+    while let Some(field) = multipart.next_field().await.map_err(|e| e.status())? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let file_name = field
+            .file_name()
+            .map(sanitize_file_name)
+            .filter(|n| !n.is_empty())
+            .unwrap_or_else(|| "foto".to_string());
+        let data = field.bytes().await.map_err(|e| e.status())?;
+        files.push((file_name, data));
+    }
+
+    if files.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    // Önce tüm dosyalar işlenir; geçersiz bir dosya varsa hiçbir şey kaydedilmeden dönülür.
+    let mut processed_files = Vec::with_capacity(files.len());
+    for (file_name, data) in files {
+        let checksum = format!("{:x}", Sha256::digest(&data));
+        let processed = tokio::task::spawn_blocking(move || photos::process(&data))
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .map_err(|e| match e {
+                PhotoError::UnsupportedFormat => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                PhotoError::Decode(_) => StatusCode::BAD_REQUEST,
+            })?;
+        processed_files.push((file_name, checksum, processed));
+    }
+
+    let mut new_photos = Vec::with_capacity(processed_files.len());
+    let mut stored_keys = Vec::new();
+    for (file_name, checksum, processed) in processed_files {
+        let key = Uuid::new_v4();
+        let new_photo = NewPropertyPhoto {
+            file_name,
+            width: processed.width as i32,
+            height: processed.height as i32,
+            checksum,
+            web_key: format!("photos/{}/web.jpg", key),
+            thumb_key: format!("photos/{}/thumb.jpg", key),
+            uploaded_by: user_id,
+        };
+
+        for (key, data) in [
+            (&new_photo.web_key, &processed.web),
+            (&new_photo.thumb_key, &processed.thumb),
+        ] {
+            if state.storage.put(key, data).await.is_err() {
+                remove_blobs(&state, &stored_keys).await;
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+            stored_keys.push(key.clone());
+        }
+        new_photos.push(new_photo);
+    }
+
+    match state
+        .db
+        .create_photos(site.site_id, property_id, &new_photos)
+        .await
+    {
+        Ok(created) => Ok((StatusCode::CREATED, Json(created))),
+        Err(e) => {
+            remove_blobs(&state, &stored_keys).await;
+            Err(match e {
+                sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            })
+        }
+    }
+}
+
+pub async fn reorder_property_photos(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    Json(body): Json<ReorderPhotos>,
+) -> Result<Json<Vec<PropertyPhoto>>, StatusCode> {
+    let current = state
+        .db
+        .list_property_photos(site.site_id, property_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut expected: Vec<Uuid> = current.iter().map(|p| p.id).collect();
+    let mut given = body.photo_ids.clone();
+    expected.sort();
+    given.sort();
+    if expected != given {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let photos = state
+        .db
+        .reorder_property_photos(site.site_id, property_id, &body.photo_ids)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(photos))
+}
+
+pub async fn set_cover_photo(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path((property_id, photo_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<PropertyPhoto>, StatusCode> {
+    let photo = state
+        .db
+        .set_cover_photo(site.site_id, property_id, photo_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(photo))
+}
+
+pub async fn photo_file(
+    State(state): State<AppState>,
+    Path((photo_id, variant)): Path<(Uuid, String)>,
+    Query(query): Query<PhotoFileQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    if !photos::verify_url_token(photo_id, &variant, &query.token) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let photo = state
+        .db
+        .get_photo_by_id(photo_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    let key = match variant.as_str() {
+        "web" => &photo.web_key,
+        "thumb" => &photo.thumb_key,
+        _ => return Err(StatusCode::NOT_FOUND),
+    };
+
+    let data = state.storage.get(key).await.map_err(|e| match e {
+        StorageError::NotFound => StatusCode::NOT_FOUND,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "image/jpeg"),
+            (header::CACHE_CONTROL, "private, max-age=3600"),
+        ],
+        data,
+    ))
+}
+
+pub async fn delete_photo(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(photo_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    let photo = state
+        .db
+        .get_photo(site.site_id, photo_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    if !site.is_manager() && photo.uploaded_by.map(|id| id.to_string()) != Some(claims.sub) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    state
+        .db
+        .delete_photo(site.site_id, photo_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    for key in [&photo.web_key, &photo.thumb_key] {
+        match state.storage.delete(key).await {
+            Ok(()) | Err(StorageError::NotFound) => {}
+            Err(e) => eprintln!("Dosya silinemedi ({}): {}", key, e),
+        }
+    }
+
+    Ok(StatusCode::OK)
+}
+
+async fn remove_blobs(state: &AppState, keys: &[String]) {
+    for key in keys {
+        let _ = state.storage.delete(key).await;
+    }
+}
@@ -1,14 +1,179 @@
-use crate::{AppState, models::PropertyWithCustomer};
-use axum::{Json, extract::State, http::StatusCode};
+use crate::{
+    AppState,
+    models::{
+        Claims, CreatePropertyTransfer, ExportQuery, Page, Pagination, PropertyFacets,
+        PropertyFilter, PropertyOwner, PropertyTransferDetail, PropertyWithCustomer,
+        SetPropertyOwners, SiteContext,
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use std::str::FromStr;
+use uuid::Uuid;
 
 pub async fn list_properties(
     State(state): State<AppState>,
-) -> Result<Json<Vec<PropertyWithCustomer>>, StatusCode> {
+    Extension(site): Extension<SiteContext>,
+    Query(filter): Query<PropertyFilter>,
+    Query(pagination): Query<Pagination>,
+) -> Result<Json<Page<PropertyWithCustomer>>, StatusCode> {
     let result = state
         .db
-        .list_properties()
+        .list_properties(site.site_id, &filter, &pagination)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     Ok(Json(result))
 }
+
+pub async fn export_properties(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(filter): Query<PropertyFilter>,
+    Query(query): Query<ExportQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    if !site.is_manager() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let rows = state
+        .db
+        .export_properties(site.site_id, &filter)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let data = crate::exports::properties(&rows, query.format)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(super::export_download(query.format, "daireler", data))
+}
+
+pub async fn property_facets(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(filter): Query<PropertyFilter>,
+) -> Result<Json<PropertyFacets>, StatusCode> {
+    let result = state
+        .db
+        .property_facets(site.site_id, &filter)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+#[derive(Deserialize)]
+pub struct OwnersQuery {
+    #[serde(default)]
+    pub include_past: bool,
+}
+
+pub async fn list_property_owners(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    Query(query): Query<OwnersQuery>,
+) -> Result<Json<Vec<PropertyOwner>>, StatusCode> {
+    let owners = state
+        .db
+        .list_property_owners(site.site_id, property_id, query.include_past)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(owners))
+}
+
+pub async fn set_property_owners(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    Json(body): Json<SetPropertyOwners>,
+) -> Result<Json<Vec<PropertyOwner>>, StatusCode> {
+    if !body.is_valid() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    state
+        .db
+        .set_property_owners(site.site_id, property_id, &body.owners)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    let owners = state
+        .db
+        .list_property_owners(site.site_id, property_id, false)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(owners))
+}
+
+pub async fn list_property_transfers(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+) -> Result<Json<Vec<PropertyTransferDetail>>, StatusCode> {
+    let transfers = state
+        .db
+        .list_property_transfers(site.site_id, property_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(transfers))
+}
+
+pub async fn create_property_transfer(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+    Json(body): Json<CreatePropertyTransfer>,
+) -> Result<(StatusCode, Json<PropertyTransferDetail>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    if !body.is_valid() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    if let Some(danisan_id) = body.danisan_id {
+        crate::handlers::customers::ensure_site_member(&state, &site, danisan_id).await?;
+    }
+
+    let latest_start = state
+        .db
+        .latest_open_owner_start(site.site_id, property_id, body.sale_date)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+    if latest_start.is_some_and(|start| body.sale_date < start) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let transfer = state
+        .db
+        .create_property_transfer(site.site_id, property_id, user_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(transfer)))
+}
@@ -1,6 +1,6 @@
 use crate::{
     AppState,
-    models::{Claims, CreateReminder, Reminder},
+    models::{Claims, CreateReminder, Reminder, SiteContext},
 };
 use axum::{
     Extension, Json,
@@ -13,6 +13,7 @@ use uuid::Uuid;
 pub async fn create_reminder(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Extension(site): Extension<SiteContext>,
     Path(customer_id): Path<Uuid>,
     Json(body): Json<CreateReminder>,
 ) -> Result<(StatusCode, Json<Reminder>), StatusCode> {
@@ -20,9 +21,29 @@ pub async fn create_reminder(
 
     let reminder = state
         .db
-        .create_reminder(customer_id, user_id, &body)
+        .create_reminder(site.site_id, customer_id, user_id, &body)
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
 
     Ok((StatusCode::CREATED, Json(reminder)))
 }
+
+pub async fn complete_reminder(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(reminder_id): Path<Uuid>,
+) -> Result<Json<Reminder>, StatusCode> {
+    let reminder = state
+        .db
+        .complete_reminder(site.site_id, reminder_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(reminder))
+}
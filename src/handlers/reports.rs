@@ -0,0 +1,78 @@
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+
+use crate::{
+    AppState,
+    models::{ConsultantReport, ReportRange, SiteContext},
+};
+
+const XLSX_CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+// Varsayılan aralık son 30 gün; en fazla bir yıl
+fn resolve_range(
+    range: &ReportRange,
+) -> Result<(chrono::NaiveDate, chrono::NaiveDate), StatusCode> {
+    let to = range.to.unwrap_or_else(crate::timezone::today);
+    let from = range.from.unwrap_or(to - chrono::Days::new(29));
+
+    if from > to || (to - from).num_days() > 366 {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    Ok((from, to))
+}
+
+async fn build_report(
+    state: &AppState,
+    site: SiteContext,
+    range: &ReportRange,
+) -> Result<ConsultantReport, StatusCode> {
+    if !site.is_manager() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let (from, to) = resolve_range(range)?;
+
+    state
+        .db
+        .consultant_report(site.site_id, from, to)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+pub async fn consultant_report(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(range): Query<ReportRange>,
+) -> Result<Json<ConsultantReport>, StatusCode> {
+    let report = build_report(&state, site, &range).await?;
+
+    Ok(Json(report))
+}
+
+pub async fn consultant_report_xlsx(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(range): Query<ReportRange>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let report = build_report(&state, site, &range).await?;
+
+    let data = crate::reports::consultant_report_xlsx(&report)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let file_name = format!("danisman-raporu-{}-{}.xlsx", report.from, report.to);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, XLSX_CONTENT_TYPE.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", file_name),
+            ),
+        ],
+        data,
+    ))
+}
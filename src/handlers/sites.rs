@@ -0,0 +1,220 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{
+        Block, Claims, CreateBlock, CreateSite, SetSiteMember, Site, SiteContext, SiteMember,
+        SiteRole, SiteTree, SiteWithRole,
+    },
+};
+
+fn site_error(e: sqlx::Error) -> StatusCode {
+    match e {
+        sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+        sqlx::Error::Database(e) if e.is_unique_violation() => StatusCode::CONFLICT,
+        sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::CONFLICT,
+        sqlx::Error::Database(e) if e.is_check_violation() => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+// Kullanıcının sitedeki rolü; üye değilse FORBIDDEN
+async fn site_role(
+    state: &AppState,
+    claims: &Claims,
+    site_id: Uuid,
+) -> Result<SiteRole, StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    state
+        .db
+        .site_role(site_id, user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::FORBIDDEN)
+}
+
+async fn require_manager(
+    state: &AppState,
+    claims: &Claims,
+    site_id: Uuid,
+) -> Result<(), StatusCode> {
+    match site_role(state, claims, site_id).await? {
+        SiteRole::Manager => Ok(()),
+        _ => Err(StatusCode::FORBIDDEN),
+    }
+}
+
+pub async fn list_sites(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<Vec<SiteWithRole>>, StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let sites = state
+        .db
+        .list_user_sites(user_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(sites))
+}
+
+pub async fn create_site(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Json(body): Json<CreateSite>,
+) -> Result<(StatusCode, Json<Site>), StatusCode> {
+    if !claims.is_admin {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let site = state.db.create_site(&body).await.map_err(site_error)?;
+
+    Ok((StatusCode::CREATED, Json(site)))
+}
+
+pub async fn update_site(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(site_id): Path<Uuid>,
+    Json(body): Json<CreateSite>,
+) -> Result<Json<Site>, StatusCode> {
+    require_manager(&state, &claims, site_id).await?;
+
+    let site = state
+        .db
+        .update_site(site_id, &body)
+        .await
+        .map_err(site_error)?;
+
+    Ok(Json(site))
+}
+
+pub async fn site_tree(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(site_id): Path<Uuid>,
+) -> Result<Json<SiteTree>, StatusCode> {
+    site_role(&state, &claims, site_id).await?;
+
+    let tree = state.db.site_tree(site_id).await.map_err(site_error)?;
+
+    Ok(Json(tree))
+}
+
+pub async fn create_block(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(site_id): Path<Uuid>,
+    Json(body): Json<CreateBlock>,
+) -> Result<(StatusCode, Json<Block>), StatusCode> {
+    require_manager(&state, &claims, site_id).await?;
+
+    let block = state
+        .db
+        .create_block(site_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            e => site_error(e),
+        })?;
+
+    Ok((StatusCode::CREATED, Json(block)))
+}
+
+pub async fn update_block(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(block_id): Path<Uuid>,
+    Json(body): Json<CreateBlock>,
+) -> Result<Json<Block>, StatusCode> {
+    if !site.is_manager() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let block = state
+        .db
+        .update_block(site.site_id, block_id, &body)
+        .await
+        .map_err(site_error)?;
+
+    Ok(Json(block))
+}
+
+pub async fn delete_block(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(block_id): Path<Uuid>,
+) -> Result<StatusCode, StatusCode> {
+    if !site.is_manager() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    state
+        .db
+        .delete_block(site.site_id, block_id)
+        .await
+        .map_err(site_error)?;
+
+    Ok(StatusCode::OK)
+}
+
+pub async fn list_site_members(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(site_id): Path<Uuid>,
+) -> Result<Json<Vec<SiteMember>>, StatusCode> {
+    site_role(&state, &claims, site_id).await?;
+
+    let members = state
+        .db
+        .list_site_members(site_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(members))
+}
+
+pub async fn set_site_member(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((site_id, user_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<SetSiteMember>,
+) -> Result<StatusCode, StatusCode> {
+    require_manager(&state, &claims, site_id).await?;
+
+    state
+        .db
+        .set_site_member(site_id, user_id, body.role)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            e => site_error(e),
+        })?;
+
+    Ok(StatusCode::OK)
+}
+
+pub async fn remove_site_member(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((site_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, StatusCode> {
+    require_manager(&state, &claims, site_id).await?;
+
+    state
+        .db
+        .remove_site_member(site_id, user_id)
+        .await
+        .map_err(site_error)?;
+
+    Ok(StatusCode::OK)
+}
@@ -0,0 +1,52 @@
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+
+use crate::{
+    AppState,
+    models::{SiteContext, StatsQuery},
+};
+
+pub fn stats_cache_ttl() -> std::time::Duration {
+    let secs = std::env::var("STATS_CACHE_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(60);
+    std::time::Duration::from_secs(secs)
+}
+
+pub async fn portfolio_stats(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(query): Query<StatsQuery>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let days = query.days.unwrap_or(30);
+    if days < 0 {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let key = (site.site_id, days);
+    let stats = match state.stats_cache.get(&key) {
+        Some(stats) => stats,
+        None => {
+            let stats = state
+                .db
+                .portfolio_stats(site.site_id, days)
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            state.stats_cache.insert(key, stats.clone());
+            stats
+        }
+    };
+
+    Ok((
+        [(
+            header::CACHE_CONTROL,
+            format!("private, max-age={}", state.stats_cache.ttl().as_secs()),
+        )],
+        Json(stats),
+    ))
+}
@@ -0,0 +1,54 @@
+use crate::{
+    AppState,
+    models::{CreateTenant, SiteContext, Tenant, TenantDetail},
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+pub async fn list_tenants(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+) -> Result<Json<Vec<Tenant>>, StatusCode> {
+    let result = state
+        .db
+        .list_tenants(site.site_id)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn create_tenant(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Json(body): Json<CreateTenant>,
+) -> Result<(StatusCode, Json<Tenant>), StatusCode> {
+    let tenant = state
+        .db
+        .create_tenant(site.site_id, &body)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok((StatusCode::CREATED, Json(tenant)))
+}
+
+pub async fn tenant_detail(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<Json<TenantDetail>, StatusCode> {
+    let tenant = state
+        .db
+        .tenant_detail(site.site_id, tenant_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(tenant))
+}
@@ -0,0 +1,180 @@
+use crate::{
+    AppState,
+    models::{
+        AssignTicket, Claims, CreateTicket, CreateTicketComment, Page, Pagination, SiteContext,
+        Ticket, TicketComment, TicketDetail, TicketFilter, TicketStatus, TicketWithDetails,
+        UpdateTicketStatus,
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub async fn list_tickets(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Query(filter): Query<TicketFilter>,
+    Query(pagination): Query<Pagination>,
+) -> Result<Json<Page<TicketWithDetails>>, StatusCode> {
+    let result = state
+        .db
+        .list_tickets(site.site_id, &filter, &pagination)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(result))
+}
+
+pub async fn list_property_tickets(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(property_id): Path<Uuid>,
+) -> Result<Json<Vec<TicketWithDetails>>, StatusCode> {
+    let result = state
+        .db
+        .list_property_tickets(site.site_id, property_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(result))
+}
+
+pub async fn ticket_detail(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Json<TicketDetail>, StatusCode> {
+    let ticket = state
+        .db
+        .ticket_detail(site.site_id, ticket_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(ticket))
+}
+
+pub async fn create_ticket(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Extension(claims): Extension<Claims>,
+    Json(body): Json<CreateTicket>,
+) -> Result<(StatusCode, Json<Ticket>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let ticket = state
+        .db
+        .create_ticket(site.site_id, user_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(ticket)))
+}
+
+pub async fn assign_ticket(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<AssignTicket>,
+) -> Result<Json<Ticket>, StatusCode> {
+    crate::handlers::customers::ensure_site_member(&state, &site, body.assigned_to).await?;
+
+    let current = state
+        .db
+        .get_ticket(site.site_id, ticket_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+    if !current.status.is_assignable() {
+        return Err(StatusCode::CONFLICT);
+    }
+
+    // Kayıt az önce okundu; güncelleme satır bulamadıysa durum bu arada değişmiştir
+    let ticket = state
+        .db
+        .assign_ticket(site.site_id, ticket_id, body.assigned_to)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::CONFLICT,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(ticket))
+}
+
+pub async fn update_ticket_status(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<UpdateTicketStatus>,
+) -> Result<Json<Ticket>, StatusCode> {
+    let current = state
+        .db
+        .get_ticket(site.site_id, ticket_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    if !current.status.can_transition_to(body.status) {
+        return Err(StatusCode::CONFLICT);
+    }
+    if body.status == TicketStatus::Assigned && current.assigned_to.is_none() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    // Yeniden açılan işin SLA süresi baştan başlar; eski vade ile hemen gecikmiş görünmez
+    let due_at = (current.status == TicketStatus::Resolved && body.status == TicketStatus::Open)
+        .then(|| chrono::Utc::now() + current.priority.sla());
+
+    let ticket = state
+        .db
+        .update_ticket_status(site.site_id, ticket_id, current.status, body.status, due_at)
+        .await
+        .map_err(|e| match e {
+            // Aynı anda başka bir istek durumu değiştirdi
+            sqlx::Error::RowNotFound => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok(Json(ticket))
+}
+
+pub async fn create_ticket_comment(
+    State(state): State<AppState>,
+    Extension(site): Extension<SiteContext>,
+    Extension(claims): Extension<Claims>,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<CreateTicketComment>,
+) -> Result<(StatusCode, Json<TicketComment>), StatusCode> {
+    let user_id = Uuid::from_str(&claims.sub).map_err(|_| StatusCode::UNAUTHORIZED)?;
+
+    let comment = state
+        .db
+        .create_ticket_comment(site.site_id, ticket_id, user_id, &body)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => StatusCode::NOT_FOUND,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        })?;
+
+    Ok((StatusCode::CREATED, Json(comment)))
+}
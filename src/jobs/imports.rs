@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::commands::import_report::{ImportReport, RowStatus};
+use crate::commands::import_run::{ImportContext, ImportFile, ImportSite};
+use crate::commands::{ImportKind, ImportOptions, import_customers, import_properties};
+use crate::database::Database;
+use crate::models::{ImportRunError, ImportStatus, MissingRecord, UnmatchedTenant};
+
+// Biten deneme ve başarısız işler bu süre boyunca sorgulanabilir
+const FINISHED_JOB_TTL: Duration = Duration::from_secs(60 * 60);
+
+struct Job {
+    site_id: Uuid,
+    status: ImportStatus,
+    finished_at: Option<Instant>,
+}
+
+// Süren içe aktarma işleri. Kaydedilen çalıştırmalar bitince buradan silinir ve
+// import_runs tablosundan okunur.
+#[derive(Default)]
+pub struct ImportJobs {
+    jobs: Mutex<HashMap<Uuid, Job>>,
+}
+
+impl ImportJobs {
+    pub fn get(&self, site_id: Uuid, id: Uuid) -> Option<ImportStatus> {
+        let jobs = self.jobs.lock().unwrap();
+        jobs.get(&id)
+            .filter(|job| job.site_id == site_id)
+            .map(|job| job.status.clone())
+    }
+
+    fn insert(&self, site_id: Uuid, status: ImportStatus) {
+        let mut jobs = self.jobs.lock().unwrap();
+        jobs.retain(|_, job| {
+            job.finished_at
+                .is_none_or(|at| at.elapsed() < FINISHED_JOB_TTL)
+        });
+        jobs.insert(
+            status.id,
+            Job {
+                site_id,
+                status,
+                finished_at: None,
+            },
+        );
+    }
+
+    fn update(&self, id: Uuid, f: impl FnOnce(&mut ImportStatus)) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(&id) {
+            f(&mut job.status);
+        }
+    }
+
+    fn finish(&self, id: Uuid, f: impl FnOnce(&mut ImportStatus)) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(&id) {
+            f(&mut job.status);
+            job.finished_at = Some(Instant::now());
+        }
+    }
+
+    fn remove(&self, id: Uuid) {
+        self.jobs.lock().unwrap().remove(&id);
+    }
+}
+
+// İlerleme bildiriminde sayaçlar her satırda, listeler ise bu aralıkla ve iş bitince kopyalanır
+const LIST_REFRESH_ROWS: usize = 200;
+
+struct ReportCounts {
+    created: i32,
+    updated: i32,
+    skipped: i32,
+    errors: i32,
+    missing: i32,
+}
+
+impl ReportCounts {
+    fn new(report: &ImportReport) -> Self {
+        let mut counts = ReportCounts {
+            created: 0,
+            updated: 0,
+            skipped: 0,
+            errors: 0,
+            missing: report.missing.len() as i32,
+        };
+        for row in &report.rows {
+            match row.status {
+                RowStatus::Create => counts.created += 1,
+                RowStatus::Update => counts.updated += 1,
+                RowStatus::Skip => counts.skipped += 1,
+                RowStatus::Error => counts.errors += 1,
+            }
+        }
+        counts
+    }
+
+    fn apply(&self, status: &mut ImportStatus) {
+        status.created_count = self.created;
+        status.updated_count = self.updated;
+        status.skipped_count = self.skipped;
+        status.error_count = self.errors;
+        status.missing_count = self.missing;
+    }
+}
+
+struct ReportLists {
+    errors: Vec<ImportRunError>,
+    unmatched_tenants: Vec<UnmatchedTenant>,
+    missing: Vec<MissingRecord>,
+}
+
+impl ReportLists {
+    fn new(report: &ImportReport) -> Self {
+        ReportLists {
+            errors: report
+                .rows
+                .iter()
+                .filter(|r| r.status == RowStatus::Error)
+                .map(|r| ImportRunError {
+                    row_number: r.row as i32 + 1,
+                    row_key: r.key.clone(),
+                    message: r.message.clone(),
+                })
+                .collect(),
+            unmatched_tenants: report.unmatched_tenants.clone(),
+            missing: report.missing.clone(),
+        }
+    }
+
+    fn apply(self, status: &mut ImportStatus) {
+        status.errors = self.errors;
+        status.unmatched_tenants = self.unmatched_tenants;
+        status.missing = self.missing;
+    }
+}
+
+fn apply_report(status: &mut ImportStatus, report: &ImportReport) {
+    ReportCounts::new(report).apply(status);
+    ReportLists::new(report).apply(status);
+}
+
+// İçe aktarmayı arka planda başlatır ve ilk durumu döner
+pub fn spawn(
+    db: Database,
+    jobs: Arc<ImportJobs>,
+    site_id: Uuid,
+    user_id: Uuid,
+    kind: ImportKind,
+    file: ImportFile,
+    options: ImportOptions,
+) -> ImportStatus {
+    let id = Uuid::new_v4();
+    let status = ImportStatus {
+        id,
+        kind: kind.as_str().to_string(),
+        file_name: file.name.clone(),
+        status: "running".to_string(),
+        dry_run: options.dry_run,
+        sync: options.sync,
+        total_rows: 0,
+        processed_rows: 0,
+        created_count: 0,
+        updated_count: 0,
+        skipped_count: 0,
+        error_count: 0,
+        errors: Vec::new(),
+        unmatched_tenants: Vec::new(),
+        missing_count: 0,
+        missing: Vec::new(),
+        message: None,
+    };
+    jobs.insert(site_id, status.clone());
+
+    tokio::spawn(async move {
+        let progress_jobs = jobs.clone();
+        let ctx = ImportContext {
+            run_id: id,
+            site: ImportSite::Id(site_id),
+            user_id: Some(user_id),
+            progress: Some(Box::new(move |processed, total, report| {
+                // Kopyalar kilit dışında hazırlanır; kilit yalnızca atama için tutulur
+                let counts = ReportCounts::new(report);
+                let lists = (processed % LIST_REFRESH_ROWS == 0 || processed == total)
+                    .then(|| ReportLists::new(report));
+                progress_jobs.update(id, |status| {
+                    status.processed_rows = processed as i32;
+                    status.total_rows = total as i32;
+                    counts.apply(status);
+                    if let Some(lists) = lists {
+                        lists.apply(status);
+                    }
+                });
+            })),
+        };
+
+        let result = match kind {
+            ImportKind::Properties => import_properties::import(&db, &file, ctx, &options).await,
+            ImportKind::Customers => import_customers::import(&db, &file, ctx, &options).await,
+        };
+
+        match result {
+            // Kaydedilen çalıştırma artık import_runs tablosunda
+            Ok(_) if !options.dry_run => jobs.remove(id),
+            Ok(report) => jobs.finish(id, |status| {
+                apply_report(status, &report);
+                status.status = "dry_run".to_string();
+            }),
+            Err(e) => {
+                eprintln!("İçe aktarma hatası ({}): {}", id, e);
+                jobs.finish(id, |status| {
+                    status.status = "failed".to_string();
+                    status.message = Some(e.to_string());
+                });
+            }
+        }
+    });
+
+    status
+}
@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use crate::database::Database;
+use crate::matching::{NOTIFY_MIN_SCORE, score_listing};
+use crate::models::{LeadWithDetails, ListingWithProperty};
+
+pub async fn run(db: Database, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(e) = run_once(&db).await {
+            eprintln!("İlan eşleştirme hatası: {}", e);
+        }
+    }
+}
+
+pub async fn run_once(db: &Database) -> Result<usize, sqlx::Error> {
+    let mut notified = 0;
+    for site in db.list_sites().await? {
+        notified += run_for_site(db, site.id).await?;
+    }
+
+    Ok(notified)
+}
+
+async fn run_for_site(db: &Database, site_id: Uuid) -> Result<usize, sqlx::Error> {
+    let listings = db.list_unmatched_listings(site_id).await?;
+    if listings.is_empty() {
+        return Ok(0);
+    }
+
+    let leads = db.list_open_leads(site_id).await?;
+    let mut notified = 0;
+
+    for listing in listings {
+        for lead in &leads {
+            if notify_match(db, site_id, lead, &listing).await? {
+                notified += 1;
+            }
+        }
+
+        db.mark_listing_matched(listing.listing.id).await?;
+    }
+
+    Ok(notified)
+}
+
+// Zamanlanmış iş yalnızca yeni ilanlara bakar; yeni ya da güncellenen müşteri isteği
+// yayındaki tüm ilanlarla ayrıca eşleştirilir
+pub async fn run_for_lead(
+    db: &Database,
+    site_id: Uuid,
+    lead_id: Uuid,
+) -> Result<usize, sqlx::Error> {
+    let Some(lead) = db.get_open_lead(site_id, lead_id).await? else {
+        return Ok(0);
+    };
+
+    let mut notified = 0;
+    for listing in db.list_active_listings(site_id).await? {
+        if notify_match(db, site_id, &lead, &listing).await? {
+            notified += 1;
+        }
+    }
+
+    Ok(notified)
+}
+
+pub fn spawn_for_lead(db: Database, site_id: Uuid, lead_id: Uuid) {
+    tokio::spawn(async move {
+        if let Err(e) = run_for_lead(&db, site_id, lead_id).await {
+            eprintln!("İlan eşleştirme hatası: {}", e);
+        }
+    });
+}
+
+// Aynı müşteri isteği ve ilan için danışmana yalnızca bir bildirim gider
+async fn notify_match(
+    db: &Database,
+    site_id: Uuid,
+    lead: &LeadWithDetails,
+    listing: &ListingWithProperty,
+) -> Result<bool, sqlx::Error> {
+    let Some(danisan_id) = lead.lead.danisan_id else {
+        return Ok(false);
+    };
+    let Some(m) = score_listing(&lead.lead, listing) else {
+        return Ok(false);
+    };
+    if m.score < NOTIFY_MIN_SCORE {
+        return Ok(false);
+    }
+
+    let title = format!("Yeni ilan eşleşmesi: {}", listing.daire_no);
+    let body = format!(
+        "{} için %{} uyum. {}",
+        lead.ad_soyad,
+        m.score,
+        m.reasons.join(", ")
+    );
+
+    db.create_match_notification(
+        site_id,
+        danisan_id,
+        lead.lead.id,
+        listing.listing.id,
+        &title,
+        &body,
+    )
+    .await
+}
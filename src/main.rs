@@ -1,21 +1,37 @@
+use std::sync::Arc;
+
 use axum::{
     Router,
-    http::{Method, header},
+    extract::DefaultBodyLimit,
+    http::{HeaderName, Method, header},
     middleware,
-    routing::{delete, get, post},
+    routing::{delete, get, post, put},
 };
 use tower_http::cors::{Any, CorsLayer};
 
 use crate::models::CreateUser;
 
+mod cache;
 mod commands;
 mod database;
+mod exports;
 mod handlers;
+mod jobs;
+mod matching;
 mod models;
+mod photos;
+mod reports;
+mod storage;
+mod timezone;
+
+const DEFAULT_SITE: &str = "Varsayılan Site";
 
 #[derive(Clone)]
 struct AppState {
     db: database::Database,
+    storage: Arc<dyn storage::Storage>,
+    stats_cache: Arc<cache::TtlCache<(uuid::Uuid, i32), models::PortfolioStats>>,
+    import_jobs: Arc<jobs::imports::ImportJobs>,
 }
 
 #[tokio::main]
@@ -28,17 +44,46 @@ async fn main() {
     if args.len() > 1 {
         match args[1].as_str() {
             "import-properties" => {
-                let filepath = args
-                    .get(2)
-                    .expect("Dosya yolu gerekli: cargo run -- import-properties dosya.xlsx");
-                commands::import_properties::run(&db, filepath).await;
+                let (args, options) = commands::parse_import_args(&args[2..]);
+                let filepath = args.first().expect(
+                    "Dosya yolu gerekli: cargo run -- import-properties dosya.xlsx|csv|ods [site adı] [--dry-run] [--sync] [--unlink-missing] [--best-effort] [--force] [--user e-posta] [--columns eşleme.toml] [--sheet ad] [--delimiter ;] [--encoding windows-1254] [--decimal-comma|--decimal-point] [--report rapor.xlsx]",
+                );
+                let site = args.get(1).map(String::as_str).unwrap_or(DEFAULT_SITE);
+                commands::import_properties::run(&db, filepath, site, &options).await;
                 return;
             }
             "import-customers" => {
-                let filepath = args
-                    .get(2)
-                    .expect("Dosya yolu gerekli: cargo run -- import-customers dosya.xlsx");
-                commands::import_customers::run(&db, filepath).await;
+                let (args, options) = commands::parse_import_args(&args[2..]);
+                let filepath = args.first().expect(
+                    "Dosya yolu gerekli: cargo run -- import-customers dosya.xlsx|csv|ods [site adı] [--dry-run] [--sync] [--unlink-missing] [--best-effort] [--force] [--user e-posta] [--columns eşleme.toml] [--sheet ad] [--delimiter ;] [--encoding windows-1254] [--decimal-comma|--decimal-point] [--report rapor.xlsx]",
+                );
+                let site = args.get(1).map(String::as_str).unwrap_or(DEFAULT_SITE);
+                commands::import_customers::run(&db, filepath, site, &options).await;
+                return;
+            }
+            "export-customers" => {
+                let (args, _) = commands::export::parse_export_args(&args[2..]);
+                let filepath = args.first().expect(
+                    "Dosya yolu gerekli: cargo run -- export-customers dosya.xlsx|csv [site adı]",
+                );
+                let site = args.get(1).map(String::as_str).unwrap_or(DEFAULT_SITE);
+                commands::export::run_customers(&db, filepath, site).await;
+                return;
+            }
+            "export-properties" => {
+                let (args, filter) = commands::export::parse_export_args(&args[2..]);
+                let filepath = args.first().expect(
+                    "Dosya yolu gerekli: cargo run -- export-properties dosya.xlsx|csv [site adı] [--filter \"blok=A&kiraci_var_mi=true\"]",
+                );
+                let site = args.get(1).map(String::as_str).unwrap_or(DEFAULT_SITE);
+                commands::export::run_properties(&db, filepath, site, filter.as_deref()).await;
+                return;
+            }
+            "match-listings" => {
+                match jobs::listing_matches::run_once(&db).await {
+                    Ok(n) => println!("{} bildirim oluşturuldu.", n),
+                    Err(e) => eprintln!("Hata: {}", e),
+                }
                 return;
             }
             "create-superuser" => {
@@ -63,25 +108,79 @@ async fn main() {
             }
             cmd => {
                 eprintln!("Bilinmeyen komut: {}", cmd);
-                eprintln!("Kullanım: cargo run -- import-properties <dosya.xlsx>");
+                eprintln!(
+                    "Kullanım: cargo run -- import-properties <dosya.xlsx|csv|ods> [site adı] [--dry-run] [--sync] [--unlink-missing] [--best-effort] [--force] [--user e-posta] [--columns eşleme.toml] [--sheet ad] [--delimiter ;] [--encoding windows-1254] [--decimal-comma|--decimal-point] [--report rapor.xlsx]"
+                );
                 return;
             }
         }
     }
 
-    let state = AppState { db };
+    let match_interval = std::env::var("MATCH_JOB_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(300);
+    tokio::spawn(jobs::listing_matches::run(
+        db.clone(),
+        std::time::Duration::from_secs(match_interval),
+    ));
+
+    let storage_dir = std::env::var("STORAGE_DIR").unwrap_or_else(|_| "./storage".to_string());
+    let state = AppState {
+        db,
+        storage: Arc::new(storage::LocalStorage::new(storage_dir)),
+        stats_cache: Arc::new(cache::TtlCache::new(handlers::stats::stats_cache_ttl())),
+        import_jobs: Arc::new(jobs::imports::ImportJobs::default()),
+    };
+    let upload_limit = DefaultBodyLimit::max(handlers::documents::max_document_bytes() + 64 * 1024);
+    let photo_upload_limit = DefaultBodyLimit::max(handlers::photos::max_photo_upload_bytes());
+    let import_upload_limit =
+        DefaultBodyLimit::max(handlers::imports::max_import_upload_bytes() + 64 * 1024);
 
-    let protected = Router::new()
+    // Siteden bağımsız uçlar: kullanıcılar, siteler ve aşama tanımları
+    let account = Router::new()
         .route(
             "/users",
             get(handlers::users::list_users).post(handlers::users::create_user),
         )
         .route("/users/me", get(handlers::users::me))
         .route("/users/:id", delete(handlers::users::delete_user))
+        .route(
+            "/sites",
+            get(handlers::sites::list_sites).post(handlers::sites::create_site),
+        )
+        .route(
+            "/sites/:id",
+            get(handlers::sites::site_tree).put(handlers::sites::update_site),
+        )
+        .route("/sites/:id/blocks", post(handlers::sites::create_block))
+        .route(
+            "/sites/:id/members",
+            get(handlers::sites::list_site_members),
+        )
+        .route(
+            "/sites/:id/members/:user_id",
+            put(handlers::sites::set_site_member).delete(handlers::sites::remove_site_member),
+        )
+        .route(
+            "/lead-stages",
+            get(handlers::leads::list_lead_stages).post(handlers::leads::create_lead_stage),
+        )
+        .route(
+            "/lead-stages/:id",
+            put(handlers::leads::update_lead_stage).delete(handlers::leads::delete_lead_stage),
+        );
+
+    // Geri kalan her şey istekte seçilen siteye göre kapsamlanır
+    let scoped = Router::new()
         .route(
             "/customers",
             get(handlers::customers::list_customers).post(handlers::customers::create_customer),
         )
+        .route(
+            "/customers/export",
+            get(handlers::customers::export_customers),
+        )
         .route(
             "/customers/:id/last-contact",
             post(handlers::customers::update_last_contact),
@@ -103,18 +202,211 @@ async fn main() {
             "/customers/:id/reminders",
             post(handlers::reminders::create_reminder),
         )
+        .route(
+            "/customers/:id/documents",
+            get(handlers::documents::list_customer_documents)
+                .post(handlers::documents::upload_customer_document)
+                .layer(upload_limit),
+        )
+        .route(
+            "/customers/:id/statement",
+            get(handlers::ledger::customer_statement),
+        )
+        .route(
+            "/blocks/:id",
+            put(handlers::sites::update_block).delete(handlers::sites::delete_block),
+        )
+        .route(
+            "/reminders/:id/complete",
+            post(handlers::reminders::complete_reminder),
+        )
+        .route("/stats", get(handlers::stats::portfolio_stats))
+        .route(
+            "/imports",
+            post(handlers::imports::create_import).layer(import_upload_limit),
+        )
+        .route("/imports/:id", get(handlers::imports::get_import))
+        .route(
+            "/reports/consultants",
+            get(handlers::reports::consultant_report),
+        )
+        .route(
+            "/reports/consultants.xlsx",
+            get(handlers::reports::consultant_report_xlsx),
+        )
         .route("/properties", get(handlers::properties::list_properties))
+        .route(
+            "/properties/export",
+            get(handlers::properties::export_properties),
+        )
+        .route(
+            "/properties/facets",
+            get(handlers::properties::property_facets),
+        )
+        .route(
+            "/properties/:id/owners",
+            get(handlers::properties::list_property_owners)
+                .put(handlers::properties::set_property_owners),
+        )
+        .route(
+            "/properties/:id/transfers",
+            get(handlers::properties::list_property_transfers)
+                .post(handlers::properties::create_property_transfer),
+        )
+        .route(
+            "/properties/:id/leases",
+            get(handlers::leases::list_property_leases).post(handlers::leases::create_lease),
+        )
+        .route(
+            "/properties/:id/ledger",
+            get(handlers::ledger::property_ledger),
+        )
+        .route(
+            "/properties/:id/ledger/charges",
+            post(handlers::ledger::create_charge),
+        )
+        .route(
+            "/properties/:id/ledger/payments",
+            post(handlers::ledger::create_payment),
+        )
+        .route(
+            "/properties/:id/tickets",
+            get(handlers::tickets::list_property_tickets),
+        )
+        .route(
+            "/properties/:id/documents",
+            get(handlers::documents::list_property_documents)
+                .post(handlers::documents::upload_property_document)
+                .layer(upload_limit),
+        )
+        .route(
+            "/properties/:id/photos",
+            get(handlers::photos::list_property_photos)
+                .post(handlers::photos::upload_property_photos)
+                .layer(photo_upload_limit),
+        )
+        .route(
+            "/properties/:id/photos/order",
+            put(handlers::photos::reorder_property_photos),
+        )
+        .route(
+            "/properties/:id/photos/:photo_id/cover",
+            post(handlers::photos::set_cover_photo),
+        )
+        .route(
+            "/properties/:id/listings",
+            get(handlers::listings::list_property_listings),
+        )
+        .route(
+            "/listings",
+            get(handlers::listings::list_listings).post(handlers::listings::create_listing),
+        )
+        .route(
+            "/listings/:id",
+            get(handlers::listings::listing_detail).put(handlers::listings::update_listing),
+        )
+        .route(
+            "/leads",
+            get(handlers::leads::list_leads).post(handlers::leads::create_lead),
+        )
+        .route("/leads/board", get(handlers::leads::lead_pipeline))
+        .route(
+            "/leads/:id",
+            get(handlers::leads::lead_detail).put(handlers::leads::update_lead),
+        )
+        .route("/leads/:id/stage", post(handlers::leads::move_lead_stage))
+        .route("/leads/:id/matches", get(handlers::leads::lead_matches))
+        .route(
+            "/appointments",
+            get(handlers::appointments::list_appointments)
+                .post(handlers::appointments::create_appointment),
+        )
+        .route("/appointments/agenda", get(handlers::appointments::agenda))
+        .route(
+            "/appointments/:id",
+            put(handlers::appointments::update_appointment),
+        )
+        .route(
+            "/notifications",
+            get(handlers::notifications::list_notifications),
+        )
+        .route(
+            "/notifications/:id/read",
+            post(handlers::notifications::mark_notification_read),
+        )
+        .route(
+            "/tickets",
+            get(handlers::tickets::list_tickets).post(handlers::tickets::create_ticket),
+        )
+        .route("/tickets/:id", get(handlers::tickets::ticket_detail))
+        .route(
+            "/tickets/:id/assign",
+            post(handlers::tickets::assign_ticket),
+        )
+        .route(
+            "/tickets/:id/status",
+            post(handlers::tickets::update_ticket_status),
+        )
+        .route(
+            "/tickets/:id/comments",
+            post(handlers::tickets::create_ticket_comment),
+        )
+        .route("/ledger/aidat", post(handlers::ledger::generate_aidat))
+        .route(
+            "/ledger/overdue",
+            get(handlers::ledger::list_overdue_charges),
+        )
+        .route(
+            "/tenants",
+            get(handlers::tenants::list_tenants).post(handlers::tenants::create_tenant),
+        )
+        .route("/tenants/:id", get(handlers::tenants::tenant_detail))
+        .route(
+            "/leases/expiring",
+            get(handlers::leases::list_expiring_leases),
+        )
+        .route(
+            "/leases/:id/status",
+            post(handlers::leases::update_lease_status),
+        )
+        .route(
+            "/leases/:id/documents",
+            get(handlers::documents::list_lease_documents)
+                .post(handlers::documents::upload_lease_document)
+                .layer(upload_limit),
+        )
+        .route(
+            "/documents/:id",
+            delete(handlers::documents::delete_document),
+        )
+        .route(
+            "/documents/:id/download",
+            get(handlers::documents::download_document),
+        )
+        .route("/photos/:id", delete(handlers::photos::delete_photo))
         .layer(middleware::from_fn_with_state(
             state.clone(),
-            handlers::auth::auth_middleware,
+            handlers::auth::site_middleware,
         ));
 
-    let public = Router::new().route("/auth/login", post(handlers::auth::login));
+    let protected = account.merge(scoped).layer(middleware::from_fn_with_state(
+        state.clone(),
+        handlers::auth::auth_middleware,
+    ));
+
+    // Fotoğraf varyantları <img src> ile açılabilsin diye başlık yerine imzalı adresle doğrulanır.
+    let public = Router::new()
+        .route("/auth/login", post(handlers::auth::login))
+        .route("/photos/:id/:variant", get(handlers::photos::photo_file));
 
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
-        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE]);
+        .allow_headers([
+            header::AUTHORIZATION,
+            header::CONTENT_TYPE,
+            HeaderName::from_static(handlers::auth::SITE_HEADER),
+        ]);
 
     let app = Router::new()
         .merge(public)
@@ -0,0 +1,310 @@
+use bigdecimal::BigDecimal;
+
+use crate::models::{Lead, ListingWithProperty};
+
+const ODA_SAYISI_WEIGHT: u32 = 30;
+const BUDGET_WEIGHT: u32 = 30;
+const NET_M2_WEIGHT: u32 = 15;
+const CEPHE_WEIGHT: u32 = 15;
+const BLOK_WEIGHT: u32 = 10;
+
+pub const NOTIFY_MIN_SCORE: u32 = 60;
+
+pub struct Score {
+    pub score: u32,
+    pub reasons: Vec<String>,
+}
+
+// Yalnızca müşterinin belirttiği kriterler puanlanır, puan bu kriterlerin toplam ağırlığına
+// göre 100 üzerinden verilir. Kriter belirtilmemişse eşleşme hesaplanmaz.
+pub fn score_listing(lead: &Lead, listing: &ListingWithProperty) -> Option<Score> {
+    if lead
+        .listing_type
+        .is_some_and(|t| t != listing.listing.listing_type)
+    {
+        return None;
+    }
+
+    let mut score = 0;
+    let mut total = 0;
+    let mut reasons = Vec::new();
+
+    if lead.oda_sayisi.is_some() {
+        total += ODA_SAYISI_WEIGHT;
+    }
+    match &lead.oda_sayisi {
+        Some(oda) if normalize(oda) == normalize(&listing.oda_sayisi) => {
+            score += ODA_SAYISI_WEIGHT;
+            reasons.push(format!("Oda sayısı eşleşiyor ({})", listing.oda_sayisi));
+        }
+        Some(oda) => reasons.push(format!(
+            "Oda sayısı farklı (istenen {}, ilan {})",
+            oda, listing.oda_sayisi
+        )),
+        None => {}
+    }
+
+    let price = &listing.listing.asking_price;
+    let has_budget = lead.min_budget.is_some() || lead.max_budget.is_some();
+    if has_budget {
+        total += BUDGET_WEIGHT;
+    }
+    if has_budget && lead.currency != listing.listing.currency {
+        reasons.push(format!(
+            "Para birimi farklı (bütçe {}, ilan {})",
+            lead.currency, listing.listing.currency
+        ));
+    } else if has_budget {
+        let above_min = lead.min_budget.as_ref().is_none_or(|min| price >= min);
+        match &lead.max_budget {
+            Some(max) if price > max => {
+                let tolerance = max * BigDecimal::from(11) / BigDecimal::from(10);
+                if *price <= tolerance {
+                    score += BUDGET_WEIGHT / 2;
+                    reasons.push("Fiyat bütçenin %10'a kadar üzerinde".to_string());
+                } else {
+                    reasons.push("Fiyat bütçenin üzerinde".to_string());
+                }
+            }
+            _ if above_min => {
+                score += BUDGET_WEIGHT;
+                reasons.push("Fiyat bütçe aralığında".to_string());
+            }
+            _ => {
+                score += BUDGET_WEIGHT / 2;
+                reasons.push("Fiyat bütçenin altında".to_string());
+            }
+        }
+    }
+
+    if lead.min_net_m2.is_some() {
+        total += NET_M2_WEIGHT;
+    }
+    match &lead.min_net_m2 {
+        Some(min) if listing.net_m2 >= *min => {
+            score += NET_M2_WEIGHT;
+            reasons.push(format!("Net m² yeterli ({})", listing.net_m2));
+        }
+        Some(min) if listing.net_m2.clone() * BigDecimal::from(10) >= min * BigDecimal::from(9) => {
+            score += NET_M2_WEIGHT / 2;
+            reasons.push(format!("Net m² biraz küçük ({} < {})", listing.net_m2, min));
+        }
+        Some(min) => reasons.push(format!("Net m² küçük ({} < {})", listing.net_m2, min)),
+        None => {}
+    }
+
+    if lead.cephe.is_some() {
+        total += CEPHE_WEIGHT;
+    }
+    match (lead.cephe, listing.cephe) {
+        (None, _) => {}
+        (Some(wanted), Some(actual)) if wanted == actual => {
+            score += CEPHE_WEIGHT;
+            reasons.push("Cephe eşleşiyor".to_string());
+        }
+        (Some(wanted), Some(actual))
+            if wanted.parts().iter().any(|p| actual.parts().contains(p)) =>
+        {
+            score += CEPHE_WEIGHT / 2;
+            reasons.push("Cephe kısmen eşleşiyor".to_string());
+        }
+        (Some(_), _) => reasons.push("Cephe farklı".to_string()),
+    }
+
+    if lead.blok.is_some() {
+        total += BLOK_WEIGHT;
+    }
+    match &lead.blok {
+        Some(blok) if normalize(blok) == normalize(&listing.blok) => {
+            score += BLOK_WEIGHT;
+            reasons.push(format!("Tercih edilen blok ({})", listing.blok));
+        }
+        Some(blok) => reasons.push(format!("Blok farklı (istenen {})", blok)),
+        None => {}
+    }
+
+    if total == 0 {
+        return None;
+    }
+    Some(Score {
+        score: (score * 100 + total / 2) / total,
+        reasons,
+    })
+}
+
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Cephe, Listing, ListingStatus, ListingType};
+    use uuid::Uuid;
+
+    fn lead() -> Lead {
+        Lead {
+            id: Uuid::nil(),
+            customer_id: Uuid::nil(),
+            stage_id: Uuid::nil(),
+            danisan_id: None,
+            listing_type: None,
+            oda_sayisi: None,
+            blok: None,
+            cephe: None,
+            min_net_m2: None,
+            min_budget: None,
+            max_budget: None,
+            currency: "TRY".to_string(),
+            notes: None,
+            lost_reason: None,
+            created_by: None,
+            created_at: chrono::Utc::now(),
+            stage_changed_at: chrono::Utc::now(),
+            closed_at: None,
+        }
+    }
+
+    fn listing() -> ListingWithProperty {
+        ListingWithProperty {
+            listing: Listing {
+                id: Uuid::nil(),
+                property_id: Uuid::nil(),
+                listing_type: ListingType::Sale,
+                asking_price: BigDecimal::from(1_000_000),
+                currency: "TRY".to_string(),
+                status: ListingStatus::Active,
+                danisan_id: None,
+                description: None,
+                published_at: None,
+                expires_at: None,
+                closed_at: None,
+                created_by: None,
+                created_at: chrono::Utc::now(),
+            },
+            daire_no: "A1-1".to_string(),
+            blok: "A1".to_string(),
+            kat: "1".to_string(),
+            oda_sayisi: "2+1".to_string(),
+            brut_m2: BigDecimal::from(110),
+            net_m2: BigDecimal::from(100),
+            cephe: Some(Cephe::KB),
+            danisan_ad_soyad: None,
+            price_per_net_m2: None,
+            price_per_brut_m2: None,
+        }
+    }
+
+    fn score(lead: &Lead, listing: &ListingWithProperty) -> u32 {
+        score_listing(lead, listing)
+            .expect("puan hesaplanmalı")
+            .score
+    }
+
+    #[test]
+    fn scores_only_specified_criteria() {
+        let mut l = lead();
+        l.oda_sayisi = Some("2 + 1".to_string());
+        assert_eq!(score(&l, &listing()), 100);
+
+        // Oda (30) tutar, blok (10) tutmaz: 30 / 40
+        l.blok = Some("B".to_string());
+        let result = score_listing(&l, &listing()).unwrap();
+        assert_eq!(result.score, 75);
+        assert_eq!(
+            result.reasons,
+            vec!["Oda sayısı eşleşiyor (2+1)", "Blok farklı (istenen B)"]
+        );
+    }
+
+    #[test]
+    fn no_criteria_means_no_score() {
+        assert!(score_listing(&lead(), &listing()).is_none());
+
+        // İlan türü puanlanmaz, yalnızca eler
+        let mut l = lead();
+        l.listing_type = Some(ListingType::Sale);
+        assert!(score_listing(&l, &listing()).is_none());
+    }
+
+    #[test]
+    fn rejects_other_listing_type() {
+        let mut l = lead();
+        l.listing_type = Some(ListingType::Rent);
+        l.oda_sayisi = Some("2+1".to_string());
+        assert!(score_listing(&l, &listing()).is_none());
+    }
+
+    #[test]
+    fn scores_budget() {
+        let mut l = lead();
+        l.min_budget = Some(BigDecimal::from(900_000));
+        l.max_budget = Some(BigDecimal::from(1_000_000));
+        assert_eq!(score(&l, &listing()), 100);
+
+        // %10'a kadar üstü yarım puan
+        l.max_budget = Some(BigDecimal::from(920_000));
+        assert_eq!(score(&l, &listing()), 50);
+
+        l.max_budget = Some(BigDecimal::from(900_000));
+        assert_eq!(score(&l, &listing()), 0);
+
+        // En düşük bütçenin altı yarım puan
+        l.min_budget = Some(BigDecimal::from(1_200_000));
+        l.max_budget = None;
+        let result = score_listing(&l, &listing()).unwrap();
+        assert_eq!(result.score, 50);
+        assert_eq!(result.reasons, vec!["Fiyat bütçenin altında"]);
+    }
+
+    #[test]
+    fn budget_in_other_currency_scores_zero() {
+        let mut l = lead();
+        l.max_budget = Some(BigDecimal::from(2_000_000));
+        l.currency = "USD".to_string();
+
+        let result = score_listing(&l, &listing()).unwrap();
+        assert_eq!(result.score, 0);
+        assert_eq!(
+            result.reasons,
+            vec!["Para birimi farklı (bütçe USD, ilan TRY)"]
+        );
+    }
+
+    #[test]
+    fn scores_net_m2_within_ninety_percent() {
+        let mut l = lead();
+        l.min_net_m2 = Some(BigDecimal::from(100));
+        assert_eq!(score(&l, &listing()), 100);
+
+        // 100 m², istenen 110'un %90'ından büyük: 7 / 15
+        l.min_net_m2 = Some(BigDecimal::from(110));
+        assert_eq!(score(&l, &listing()), 47);
+
+        // 100 m², 112'nin %90'ından (100,8) küçük
+        l.min_net_m2 = Some(BigDecimal::from(112));
+        assert_eq!(score(&l, &listing()), 0);
+    }
+
+    #[test]
+    fn scores_partial_cephe() {
+        let mut l = lead();
+        l.cephe = Some(Cephe::KB);
+        assert_eq!(score(&l, &listing()), 100);
+
+        l.cephe = Some(Cephe::KbKd);
+        let result = score_listing(&l, &listing()).unwrap();
+        assert_eq!(result.score, 47);
+        assert_eq!(result.reasons, vec!["Cephe kısmen eşleşiyor"]);
+
+        l.cephe = Some(Cephe::GD);
+        assert_eq!(score(&l, &listing()), 0);
+
+        let mut no_cephe = listing();
+        no_cephe.cephe = None;
+        assert_eq!(score(&l, &no_cephe), 0);
+    }
+}
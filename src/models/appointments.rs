@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "appointment_status_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AppointmentStatus {
+    Scheduled,
+    Completed,
+    Cancelled,
+    NoShow,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Appointment {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub customer_id: Uuid,
+    pub danisan_id: Uuid,
+    pub lead_id: Option<Uuid>,
+    pub starts_at: chrono::DateTime<chrono::Utc>,
+    pub ends_at: chrono::DateTime<chrono::Utc>,
+    pub status: AppointmentStatus,
+    pub notes: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct AppointmentWithDetails {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub appointment: Appointment,
+    pub daire_no: String,
+    pub ad_soyad: String,
+    pub gsm: Option<String>,
+    pub danisan_ad_soyad: String,
+    #[serde(skip)]
+    pub agenda_date: chrono::NaiveDate,
+}
+
+#[derive(Serialize)]
+pub struct AgendaDay {
+    pub date: chrono::NaiveDate,
+    pub appointments: Vec<AppointmentWithDetails>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateAppointment {
+    pub property_id: Uuid,
+    pub customer_id: Uuid,
+    pub danisan_id: Option<Uuid>,
+    pub lead_id: Option<Uuid>,
+    pub starts_at: chrono::DateTime<chrono::Utc>,
+    pub ends_at: chrono::DateTime<chrono::Utc>,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateAppointment {
+    pub starts_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub ends_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub status: Option<AppointmentStatus>,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AgendaRange {
+    Day,
+    Week,
+}
+
+#[derive(Deserialize)]
+pub struct AgendaQuery {
+    pub danisan_id: Option<Uuid>,
+    pub date: Option<chrono::NaiveDate>,
+    pub range: Option<AgendaRange>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct AppointmentFilter {
+    pub property_id: Option<Uuid>,
+    pub customer_id: Option<Uuid>,
+    pub danisan_id: Option<Uuid>,
+    pub status: Option<AppointmentStatus>,
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::models::{Property, Reminder};
+use crate::models::{OwnedProperty, OwnershipHistoryEntry, Reminder, TicketWithDetails};
 
 #[derive(Serialize, sqlx::FromRow)]
 pub struct Customer {
@@ -35,8 +35,10 @@ pub struct CustomerWithProperties {
 pub struct CustomerDetail {
     pub customer_info: Customer,
     pub customer_notes: Vec<CustomerNote>,
-    pub customer_properties: Vec<Property>,
+    pub customer_properties: Vec<OwnedProperty>,
+    pub customer_ownership_history: Vec<OwnershipHistoryEntry>,
     pub customer_reminders: Vec<Reminder>,
+    pub customer_tickets: Vec<TicketWithDetails>,
 }
 
 #[derive(Deserialize)]
@@ -0,0 +1,263 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Blok ile kapı no arasında kabul edilen ayırıcılar
+const SEPARATORS: &[char] = &['-', '/', '.', ' '];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DaireNoError {
+    #[error("Daire no boş")]
+    Empty,
+    #[error("Geçersiz blok: {0}")]
+    InvalidBlok(String),
+    #[error("Geçersiz kapı no: {0}")]
+    InvalidKapiNo(String),
+    #[error("Daire no çözümlenemedi: {0} (blok ve kapı no'yu tire ile ayırın, örn. A1-12)")]
+    Ambiguous(String),
+}
+
+// Sitedeki bir dairenin kimliği. Kanonik biçim "BLOK-KAPI"dır: blok büyük harf ve
+// rakamlardan oluşur, kapı no baştaki sıfırlar atılarak yazılır (A1-12). Veritabanındaki
+// properties_daire_no_format kısıtı aynı biçimi zorlar.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DaireNo {
+    blok: String,
+    kapi_no: i32,
+}
+
+impl DaireNo {
+    pub fn new(blok: &str, kapi_no: i32) -> Result<Self, DaireNoError> {
+        if kapi_no < 0 {
+            return Err(DaireNoError::InvalidKapiNo(kapi_no.to_string()));
+        }
+        Ok(Self {
+            blok: canonical_blok(blok)?,
+            kapi_no,
+        })
+    }
+
+    pub fn blok(&self) -> &str {
+        &self.blok
+    }
+
+    pub fn kapi_no(&self) -> i32 {
+        self.kapi_no
+    }
+
+    // Ayırıcısız yazımlar: son üç rakam kapı no, öncesi blok (A1001 → A1-1, B012 → B-12)
+    fn parse_compact(value: &str) -> Result<Self, DaireNoError> {
+        let split = value
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or_else(|| DaireNoError::InvalidKapiNo(value.to_string()))?;
+        let (letters, digits) = value.split_at(split);
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(DaireNoError::Ambiguous(value.to_string()));
+        }
+
+        let (blok_digits, kapi_digits) = match digits.len() {
+            n if n > 3 => digits.split_at(n - 3),
+            3 if !letters.is_empty() => ("", digits),
+            _ => return Err(DaireNoError::Ambiguous(value.to_string())),
+        };
+        let blok = format!("{}{}", letters, blok_digits.trim_start_matches('0'));
+        Self::new(&blok, parse_kapi_no(kapi_digits)?)
+    }
+}
+
+fn parse_kapi_no(value: &str) -> Result<i32, DaireNoError> {
+    let value = value.trim();
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(DaireNoError::InvalidKapiNo(value.to_string()));
+    }
+    value
+        .parse()
+        .map_err(|_| DaireNoError::InvalidKapiNo(value.to_string()))
+}
+
+// Boşluk ve ayırıcılar atılır, Türkçe kurallarla büyük harfe çevrilir ("a-1 " → "A1")
+fn canonical_blok(value: &str) -> Result<String, DaireNoError> {
+    let blok: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !SEPARATORS.contains(c))
+        .flat_map(|c| match c {
+            'i' => vec!['İ'],
+            'ı' => vec!['I'],
+            c => c.to_uppercase().collect(),
+        })
+        .collect();
+
+    let valid = |c: char| c.is_ascii_uppercase() || c.is_ascii_digit() || "ÇĞİÖŞÜ".contains(c);
+    if blok.is_empty() || !blok.chars().all(valid) {
+        return Err(DaireNoError::InvalidBlok(value.trim().to_string()));
+    }
+    Ok(blok)
+}
+
+impl FromStr for DaireNo {
+    type Err = DaireNoError;
+
+    // "A1-12", "a1 / 012", "A1-012" ve "A1012" aynı daireyi gösterir
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(DaireNoError::Empty);
+        }
+
+        match value.rfind(SEPARATORS) {
+            Some(pos) => {
+                let (blok, kapi_no) = (&value[..pos], &value[pos + 1..]);
+                Self::new(blok, parse_kapi_no(kapi_no)?)
+            }
+            None => Self::parse_compact(value),
+        }
+    }
+}
+
+impl fmt::Display for DaireNo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.blok, self.kapi_no)
+    }
+}
+
+impl Serialize for DaireNo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for DaireNo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_to_canonical_form() {
+        let cases = [
+            // Kanonik biçim olduğu gibi kalır
+            ("A1-1", "A1-1"),
+            ("A1-12", "A1-12"),
+            ("A1-123", "A1-123"),
+            ("B-5", "B-5"),
+            ("AB12-7", "AB12-7"),
+            ("1-1", "1-1"),
+            ("A1-0", "A1-0"),
+            // Baştaki sıfırlar atılır
+            ("A1-001", "A1-1"),
+            ("A1-010", "A1-10"),
+            ("A1-000", "A1-0"),
+            // Küçük harf ve Türkçe karakterler
+            ("a1-1", "A1-1"),
+            ("ç2-4", "Ç2-4"),
+            ("i1-3", "İ1-3"),
+            ("ı1-3", "I1-3"),
+            ("Ş-7", "Ş-7"),
+            // Boşluklar ve farklı ayırıcılar
+            ("  A1-1  ", "A1-1"),
+            ("A1 - 1", "A1-1"),
+            ("A1 1", "A1-1"),
+            ("A1/1", "A1-1"),
+            ("A1.1", "A1-1"),
+            ("A1 / 012", "A1-12"),
+            ("A 1-1", "A1-1"),
+            // Blok içindeki tire, eski içe aktarmalarda blok adı "A1-" idi
+            ("A-1-1", "A1-1"),
+            ("A1--1", "A1-1"),
+            // Ayırıcısız yazımlarda son üç rakam kapı no
+            ("A1001", "A1-1"),
+            ("A1012", "A1-12"),
+            ("A1123", "A1-123"),
+            ("A12015", "A12-15"),
+            ("a1001", "A1-1"),
+            ("AB3002", "AB3-2"),
+            ("B012", "B-12"),
+            ("B100", "B-100"),
+            ("A01001", "A1-1"),
+            ("A0001", "A-1"),
+            ("1001", "1-1"),
+            ("12005", "12-5"),
+        ];
+
+        for (input, expected) in cases {
+            let parsed: DaireNo = input
+                .parse()
+                .unwrap_or_else(|e| panic!("{:?} çözümlenemedi: {}", input, e));
+            assert_eq!(parsed.to_string(), expected, "girdi: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        let cases = [
+            ("", DaireNoError::Empty),
+            ("   ", DaireNoError::Empty),
+            ("A1-", DaireNoError::InvalidKapiNo(String::new())),
+            ("A1-x", DaireNoError::InvalidKapiNo("x".into())),
+            ("A1-1a", DaireNoError::InvalidKapiNo("1a".into())),
+            ("A1--", DaireNoError::InvalidKapiNo(String::new())),
+            ("A1-+1", DaireNoError::InvalidKapiNo("+1".into())),
+            (
+                "A1-99999999999",
+                DaireNoError::InvalidKapiNo("99999999999".into()),
+            ),
+            ("-1", DaireNoError::InvalidBlok(String::new())),
+            ("A_1-1", DaireNoError::InvalidBlok("A_1".into())),
+            ("Ä1-1", DaireNoError::InvalidBlok("Ä1".into())),
+            ("ABC", DaireNoError::InvalidKapiNo("ABC".into())),
+            ("A1", DaireNoError::Ambiguous("A1".into())),
+            ("A12", DaireNoError::Ambiguous("A12".into())),
+            ("123", DaireNoError::Ambiguous("123".into())),
+            ("A1B001", DaireNoError::Ambiguous("A1B001".into())),
+            ("A_001", DaireNoError::InvalidBlok("A_".into())),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                input.parse::<DaireNo>(),
+                Err(expected),
+                "girdi: {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn builds_from_parts() {
+        let cases = [
+            ("A1", 1, "A1-1"),
+            ("A1-", 12, "A1-12"),
+            ("a1 ", 3, "A1-3"),
+            ("Ğ", 0, "Ğ-0"),
+        ];
+        for (blok, kapi_no, expected) in cases {
+            let daire_no = DaireNo::new(blok, kapi_no).unwrap();
+            assert_eq!(daire_no.to_string(), expected);
+            assert_eq!(daire_no.kapi_no(), kapi_no);
+            assert_eq!(daire_no.to_string().parse(), Ok(daire_no));
+        }
+
+        assert_eq!(
+            DaireNo::new("", 1),
+            Err(DaireNoError::InvalidBlok(String::new()))
+        );
+        assert_eq!(
+            DaireNo::new("A1", -1),
+            Err(DaireNoError::InvalidKapiNo("-1".into()))
+        );
+    }
+
+    #[test]
+    fn serializes_as_string() {
+        let daire_no: DaireNo = serde_json::from_str("\"a1001\"").unwrap();
+        assert_eq!(daire_no.blok(), "A1");
+        assert_eq!(serde_json::to_string(&daire_no).unwrap(), "\"A1-1\"");
+        assert!(serde_json::from_str::<DaireNo>("\"A1\"").is_err());
+    }
+}
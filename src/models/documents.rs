@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub const ALLOWED_DOCUMENT_TYPES: &[&str] = &[
+    "application/pdf",
+    "image/jpeg",
+    "image/png",
+    "image/webp",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "document_category_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentCategory {
+    Tapu,
+    KiraSozlesmesi,
+    Kimlik,
+    Fotograf,
+    Diger,
+}
+
+#[derive(Clone, Copy)]
+pub enum DocumentOwner {
+    Customer(Uuid),
+    Property(Uuid),
+    Lease(Uuid),
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Document {
+    pub id: Uuid,
+    pub customer_id: Option<Uuid>,
+    pub property_id: Option<Uuid>,
+    pub lease_id: Option<Uuid>,
+    pub category: DocumentCategory,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub checksum: String,
+    #[serde(skip)]
+    pub storage_key: String,
+    pub uploaded_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct NewDocument {
+    pub owner: DocumentOwner,
+    pub category: DocumentCategory,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub checksum: String,
+    pub storage_key: String,
+    pub uploaded_by: Uuid,
+}
@@ -0,0 +1,80 @@
+use bigdecimal::BigDecimal;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Xlsx,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    // Komut satırında biçim dosya uzantısından belirlenir
+    pub fn from_path(path: &str) -> Option<Self> {
+        let ext = std::path::Path::new(path)
+            .extension()?
+            .to_string_lossy()
+            .to_lowercase();
+        match ext.as_str() {
+            "xlsx" => Some(ExportFormat::Xlsx),
+            "csv" => Some(ExportFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ExportQuery {
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct CustomerExportRow {
+    pub ad_soyad: String,
+    pub gsm: Option<String>,
+    pub telefon: Option<String>,
+    pub email: Option<String>,
+    pub acil_kisi: Option<String>,
+    pub uyruk: Option<String>,
+    pub danisan: Option<String>,
+    pub en_son_gorusuldu: Option<chrono::DateTime<chrono::Utc>>,
+    pub property_count: i64,
+    pub daire_nolar: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct PropertyExportRow {
+    pub daire_no: String,
+    pub blok: String,
+    pub kat: String,
+    pub kapi_no: i32,
+    pub daire_tipi: String,
+    pub oda_sayisi: String,
+    pub brut_m2: BigDecimal,
+    pub net_m2: BigDecimal,
+    pub balkon_m2: Option<BigDecimal>,
+    pub cephe: Option<String>,
+    pub kiraci_var_mi: bool,
+    pub sahip_sayisi: i64,
+    pub sahipler: Option<String>,
+    pub danismanlar: Option<String>,
+    pub en_son_gorusuldu: Option<chrono::DateTime<chrono::Utc>>,
+}
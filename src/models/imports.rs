@@ -0,0 +1,86 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct ImportRun {
+    pub id: Uuid,
+    pub site_id: Uuid,
+    pub kind: String,
+    pub file_name: String,
+    pub file_hash: String,
+    pub mode: String,
+    pub status: String,
+    pub created_count: i32,
+    pub updated_count: i32,
+    pub skipped_count: i32,
+    pub error_count: i32,
+    pub sync: bool,
+    pub missing_count: i32,
+    pub user_id: Option<Uuid>,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub finished_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct NewImportRun {
+    pub id: Uuid,
+    pub site_id: Uuid,
+    pub kind: &'static str,
+    pub file_name: String,
+    pub file_hash: String,
+    pub mode: &'static str,
+    pub status: &'static str,
+    pub created_count: i32,
+    pub updated_count: i32,
+    pub skipped_count: i32,
+    pub error_count: i32,
+    pub sync: bool,
+    pub missing_count: i32,
+    pub user_id: Option<Uuid>,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Clone, Serialize, sqlx::FromRow)]
+pub struct ImportRunError {
+    pub row_number: i32,
+    pub row_key: String,
+    pub message: String,
+}
+
+// Müşteri dosyasındaki kiracısı bir daireye bağlanamayan satır
+#[derive(Clone, Serialize, sqlx::FromRow)]
+pub struct UnmatchedTenant {
+    pub row_number: i32,
+    pub daire_no: String,
+    pub ad_soyad: String,
+    pub reason: String,
+}
+
+// Eşitlemede veritabanında olup dosyada bulunmayan daire ya da sahip
+#[derive(Clone, Serialize, sqlx::FromRow)]
+pub struct MissingRecord {
+    pub row_key: String,
+    pub message: String,
+}
+
+// GET /imports/:id yanıtı; süren işler bellekten, bitenler import_runs'tan okunur
+#[derive(Clone, Serialize)]
+pub struct ImportStatus {
+    pub id: Uuid,
+    pub kind: String,
+    pub file_name: String,
+    // running, committed, rolled_back, dry_run veya failed
+    pub status: String,
+    pub dry_run: bool,
+    pub sync: bool,
+    pub total_rows: i32,
+    pub processed_rows: i32,
+    pub created_count: i32,
+    pub updated_count: i32,
+    pub skipped_count: i32,
+    pub error_count: i32,
+    pub errors: Vec<ImportRunError>,
+    pub unmatched_tenants: Vec<UnmatchedTenant>,
+    pub missing_count: i32,
+    pub missing: Vec<MissingRecord>,
+    pub message: Option<String>,
+}
@@ -0,0 +1,109 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{Cephe, CreateCustomer, ListingType};
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct LeadStage {
+    pub id: Uuid,
+    pub name: String,
+    pub position: i32,
+    pub is_won: bool,
+    pub is_lost: bool,
+}
+
+#[derive(Deserialize)]
+pub struct CreateLeadStage {
+    pub name: String,
+    pub position: i32,
+    #[serde(default)]
+    pub is_won: bool,
+    #[serde(default)]
+    pub is_lost: bool,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Lead {
+    pub id: Uuid,
+    pub customer_id: Uuid,
+    pub stage_id: Uuid,
+    pub danisan_id: Option<Uuid>,
+    pub listing_type: Option<ListingType>,
+    pub oda_sayisi: Option<String>,
+    pub blok: Option<String>,
+    pub cephe: Option<Cephe>,
+    pub min_net_m2: Option<BigDecimal>,
+    pub min_budget: Option<BigDecimal>,
+    pub max_budget: Option<BigDecimal>,
+    pub currency: String,
+    pub notes: Option<String>,
+    pub lost_reason: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub stage_changed_at: chrono::DateTime<chrono::Utc>,
+    pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct LeadWithDetails {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub lead: Lead,
+    pub ad_soyad: String,
+    pub gsm: Option<String>,
+    pub stage_name: String,
+    pub danisan_ad_soyad: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct PipelineColumn {
+    pub stage: LeadStage,
+    pub leads: Vec<LeadWithDetails>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateLead {
+    pub customer_id: Option<Uuid>,
+    pub prospect: Option<CreateCustomer>,
+    pub stage_id: Option<Uuid>,
+    pub danisan_id: Option<Uuid>,
+    pub listing_type: Option<ListingType>,
+    pub oda_sayisi: Option<String>,
+    pub blok: Option<String>,
+    pub cephe: Option<Cephe>,
+    pub min_net_m2: Option<BigDecimal>,
+    pub min_budget: Option<BigDecimal>,
+    pub max_budget: Option<BigDecimal>,
+    pub currency: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateLead {
+    pub danisan_id: Option<Uuid>,
+    pub listing_type: Option<ListingType>,
+    pub oda_sayisi: Option<String>,
+    pub blok: Option<String>,
+    pub cephe: Option<Cephe>,
+    pub min_net_m2: Option<BigDecimal>,
+    pub min_budget: Option<BigDecimal>,
+    pub max_budget: Option<BigDecimal>,
+    pub currency: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct MoveLeadStage {
+    pub stage_id: Uuid,
+    pub lost_reason: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct LeadFilter {
+    pub stage_id: Option<Uuid>,
+    pub danisan_id: Option<Uuid>,
+    pub customer_id: Option<Uuid>,
+    #[serde(default)]
+    pub include_closed: bool,
+}
@@ -0,0 +1,58 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "lease_status_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LeaseStatus {
+    Draft,
+    Active,
+    Ended,
+    Terminated,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Lease {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub tenant_id: Uuid,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: Option<chrono::NaiveDate>,
+    pub monthly_rent: Option<BigDecimal>,
+    pub currency: String,
+    pub deposit: Option<BigDecimal>,
+    pub status: LeaseStatus,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct LeaseWithDetails {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub lease: Lease,
+    pub daire_no: String,
+    pub kiraci_ad_soyad: String,
+}
+
+#[derive(Deserialize)]
+pub struct CreateLease {
+    pub tenant_id: Uuid,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: Option<chrono::NaiveDate>,
+    pub monthly_rent: Option<BigDecimal>,
+    pub currency: Option<String>,
+    pub deposit: Option<BigDecimal>,
+    pub status: Option<LeaseStatus>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateLeaseStatus {
+    pub status: LeaseStatus,
+    pub end_date: Option<chrono::NaiveDate>,
+}
+
+#[derive(Deserialize)]
+pub struct ExpiringLeasesQuery {
+    pub days: Option<i32>,
+}
@@ -0,0 +1,123 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "charge_kind_enum", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ChargeKind {
+    Aidat,
+    Kira,
+    Diger,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct LedgerCharge {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub customer_id: Option<Uuid>,
+    pub lease_id: Option<Uuid>,
+    pub kind: ChargeKind,
+    pub period: Option<chrono::NaiveDate>,
+    pub amount: BigDecimal,
+    pub currency: String,
+    pub due_date: chrono::NaiveDate,
+    pub description: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct LedgerChargeWithBalance {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub charge: LedgerCharge,
+    pub daire_no: String,
+    pub paid_amount: BigDecimal,
+    pub remaining: BigDecimal,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct LedgerPayment {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub customer_id: Option<Uuid>,
+    pub charge_id: Option<Uuid>,
+    pub amount: BigDecimal,
+    pub currency: String,
+    pub paid_at: chrono::NaiveDate,
+    pub method: Option<String>,
+    pub reference: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct LedgerBalance {
+    pub currency: String,
+    pub charged: BigDecimal,
+    pub paid: BigDecimal,
+    pub balance: BigDecimal,
+}
+
+#[derive(Serialize)]
+pub struct PropertyLedger {
+    pub charges: Vec<LedgerChargeWithBalance>,
+    pub payments: Vec<LedgerPayment>,
+    pub balances: Vec<LedgerBalance>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateCharge {
+    pub customer_id: Option<Uuid>,
+    pub lease_id: Option<Uuid>,
+    pub kind: ChargeKind,
+    pub period: Option<chrono::NaiveDate>,
+    pub amount: BigDecimal,
+    pub currency: Option<String>,
+    pub due_date: chrono::NaiveDate,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CreatePayment {
+    pub customer_id: Option<Uuid>,
+    pub charge_id: Option<Uuid>,
+    pub amount: BigDecimal,
+    pub currency: Option<String>,
+    pub paid_at: Option<chrono::NaiveDate>,
+    pub method: Option<String>,
+    pub reference: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct GenerateAidat {
+    pub period: chrono::NaiveDate,
+    pub rate_per_m2: BigDecimal,
+    pub currency: Option<String>,
+    pub due_date: Option<chrono::NaiveDate>,
+}
+
+#[derive(Serialize)]
+pub struct GenerateAidatResult {
+    pub created: u64,
+    pub skipped: u64,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct StatementEntry {
+    pub entry_date: chrono::NaiveDate,
+    pub entry_type: String,
+    pub daire_no: String,
+    pub description: Option<String>,
+    pub currency: String,
+    pub debit: BigDecimal,
+    pub credit: BigDecimal,
+    pub balance: BigDecimal,
+}
+
+#[derive(Deserialize)]
+pub struct StatementQuery {
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+}
@@ -0,0 +1,108 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::Cephe;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "listing_type_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ListingType {
+    Sale,
+    Rent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "listing_status_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ListingStatus {
+    Draft,
+    Active,
+    Reserved,
+    Closed,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Listing {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub listing_type: ListingType,
+    pub asking_price: BigDecimal,
+    pub currency: String,
+    pub status: ListingStatus,
+    pub danisan_id: Option<Uuid>,
+    pub description: Option<String>,
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct ListingWithProperty {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub listing: Listing,
+    pub daire_no: String,
+    pub blok: String,
+    pub kat: String,
+    pub oda_sayisi: String,
+    pub brut_m2: BigDecimal,
+    pub net_m2: BigDecimal,
+    pub cephe: Option<Cephe>,
+    pub danisan_ad_soyad: Option<String>,
+    pub price_per_net_m2: Option<BigDecimal>,
+    pub price_per_brut_m2: Option<BigDecimal>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateListing {
+    pub property_id: Uuid,
+    pub listing_type: ListingType,
+    pub asking_price: BigDecimal,
+    pub currency: Option<String>,
+    pub status: Option<ListingStatus>,
+    pub danisan_id: Option<Uuid>,
+    pub description: Option<String>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateListing {
+    pub asking_price: Option<BigDecimal>,
+    pub currency: Option<String>,
+    pub status: Option<ListingStatus>,
+    pub danisan_id: Option<Uuid>,
+    pub description: Option<String>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ListingFilter {
+    pub property_id: Option<Uuid>,
+    pub listing_type: Option<ListingType>,
+    pub status: Option<ListingStatus>,
+    pub danisan_id: Option<Uuid>,
+    pub blok: Option<String>,
+    pub oda_sayisi: Option<String>,
+    pub cephe: Option<String>,
+    pub currency: Option<String>,
+    pub min_price: Option<BigDecimal>,
+    pub max_price: Option<BigDecimal>,
+    pub min_net_m2: Option<BigDecimal>,
+    pub max_net_m2: Option<BigDecimal>,
+}
+
+#[derive(Serialize)]
+pub struct ListingMatch {
+    pub listing: ListingWithProperty,
+    pub score: u32,
+    pub reasons: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct MatchQuery {
+    pub limit: Option<usize>,
+    pub min_score: Option<u32>,
+}
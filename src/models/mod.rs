@@ -7,8 +7,62 @@ pub use auth::*;
 mod customer;
 pub use customer::*;
 
+mod daire_no;
+pub use daire_no::*;
+
 mod properties;
 pub use properties::*;
 
 mod reminders;
 pub use reminders::*;
+
+mod pagination;
+pub use pagination::*;
+
+mod ownership;
+pub use ownership::*;
+
+mod tenants;
+pub use tenants::*;
+
+mod leases;
+pub use leases::*;
+
+mod ledger;
+pub use ledger::*;
+
+mod tickets;
+pub use tickets::*;
+
+mod listings;
+pub use listings::*;
+
+mod leads;
+pub use leads::*;
+
+mod notifications;
+pub use notifications::*;
+
+mod appointments;
+pub use appointments::*;
+
+mod documents;
+pub use documents::*;
+
+mod photos;
+pub use photos::*;
+
+mod sites;
+pub use sites::*;
+
+mod reports;
+pub use reports::*;
+
+mod stats;
+pub use stats::*;
+
+mod imports;
+pub use imports::*;
+
+mod exports;
+pub use exports::*;
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub site_id: Option<Uuid>,
+    pub title: String,
+    pub body: Option<String>,
+    pub lead_id: Option<Uuid>,
+    pub listing_id: Option<Uuid>,
+    pub read_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Deserialize)]
+pub struct NotificationQuery {
+    #[serde(default)]
+    pub unread_only: bool,
+}
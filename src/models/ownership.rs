@@ -0,0 +1,118 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::Property;
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct PropertyOwner {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub customer_id: Uuid,
+    pub ad_soyad: String,
+    pub share_percent: BigDecimal,
+    pub start_date: Option<chrono::NaiveDate>,
+    pub end_date: Option<chrono::NaiveDate>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct OwnedProperty {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub property: Property,
+    pub share_percent: BigDecimal,
+    pub start_date: Option<chrono::NaiveDate>,
+}
+
+#[derive(Deserialize)]
+pub struct NewPropertyOwner {
+    pub customer_id: Uuid,
+    pub share_percent: BigDecimal,
+    pub start_date: Option<chrono::NaiveDate>,
+}
+
+#[derive(Deserialize)]
+pub struct SetPropertyOwners {
+    pub owners: Vec<NewPropertyOwner>,
+}
+
+impl SetPropertyOwners {
+    // Boş liste dairenin tüm sahipliğini sonlandırır; aksi halde hisselerin toplamı 100 olmalı
+    pub fn is_valid(&self) -> bool {
+        let total: BigDecimal = self.owners.iter().map(|o| &o.share_percent).sum();
+        valid_owner_shares(&self.owners) && (self.owners.is_empty() || total == 100)
+    }
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct PropertyTransfer {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub sale_date: chrono::NaiveDate,
+    pub sale_price: Option<BigDecimal>,
+    pub currency: String,
+    pub danisan_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize)]
+pub struct PropertyTransferDetail {
+    #[serde(flatten)]
+    pub transfer: PropertyTransfer,
+    pub previous_owners: Vec<PropertyOwner>,
+    pub new_owners: Vec<PropertyOwner>,
+}
+
+#[derive(Deserialize)]
+pub struct CreatePropertyTransfer {
+    pub sale_date: chrono::NaiveDate,
+    pub sale_price: Option<BigDecimal>,
+    pub currency: Option<String>,
+    pub danisan_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub owners: Vec<NewPropertyOwner>,
+}
+
+impl CreatePropertyTransfer {
+    // Devirde yeni sahiplerin hisseleri toplamı da 100 olmalı
+    pub fn is_valid(&self) -> bool {
+        let zero = BigDecimal::from(0);
+        let total: BigDecimal = self.owners.iter().map(|o| &o.share_percent).sum();
+
+        !self.owners.is_empty()
+            && valid_owner_shares(&self.owners)
+            && total == 100
+            && self.sale_price.as_ref().is_none_or(|p| *p >= zero)
+    }
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct OwnershipHistoryEntry {
+    pub property_id: Uuid,
+    pub daire_no: String,
+    pub share_percent: BigDecimal,
+    pub start_date: Option<chrono::NaiveDate>,
+    pub end_date: Option<chrono::NaiveDate>,
+    pub transfer_in_id: Option<Uuid>,
+    pub transfer_out_id: Option<Uuid>,
+}
+
+fn valid_owner_shares(owners: &[NewPropertyOwner]) -> bool {
+    let zero = BigDecimal::from(0);
+    let hundred = BigDecimal::from(100);
+
+    let mut customers: Vec<Uuid> = owners.iter().map(|o| o.customer_id).collect();
+    customers.sort();
+    customers.dedup();
+    if customers.len() != owners.len() {
+        return false;
+    }
+
+    let total: BigDecimal = owners.iter().map(|o| &o.share_percent).sum();
+    owners
+        .iter()
+        .all(|o| o.share_percent > zero && o.share_percent <= hundred)
+        && total <= hundred
+}
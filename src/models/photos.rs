@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct PropertyPhoto {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub position: i32,
+    pub is_cover: bool,
+    pub file_name: String,
+    pub width: i32,
+    pub height: i32,
+    pub checksum: String,
+    #[serde(skip)]
+    pub web_key: String,
+    #[serde(skip)]
+    pub thumb_key: String,
+    #[sqlx(skip)]
+    pub web_url: String,
+    #[sqlx(skip)]
+    pub thumbnail_url: String,
+    pub uploaded_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub struct NewPropertyPhoto {
+    pub file_name: String,
+    pub width: i32,
+    pub height: i32,
+    pub checksum: String,
+    pub web_key: String,
+    pub thumb_key: String,
+    pub uploaded_by: Uuid,
+}
+
+#[derive(Deserialize)]
+pub struct ReorderPhotos {
+    pub photo_ids: Vec<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct PhotoFileQuery {
+    pub token: String,
+}
@@ -2,7 +2,9 @@ use bigdecimal::BigDecimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Serialize, Deserialize, sqlx::Type)]
+use crate::models::DaireNo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "cephe_enum")]
 pub enum Cephe {
     #[sqlx(rename = "KB")]
@@ -19,9 +21,34 @@ pub enum Cephe {
     KdKb,
 }
 
+impl Cephe {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Cephe::KB => "KB",
+            Cephe::KD => "KD",
+            Cephe::GB => "GB",
+            Cephe::GD => "GD",
+            Cephe::KbKd => "KB-KD",
+            Cephe::KdKb => "KD-KB",
+        }
+    }
+
+    pub fn parts(self) -> &'static [Cephe] {
+        match self {
+            Cephe::KbKd | Cephe::KdKb => &[Cephe::KB, Cephe::KD],
+            Cephe::KB => &[Cephe::KB],
+            Cephe::KD => &[Cephe::KD],
+            Cephe::GB => &[Cephe::GB],
+            Cephe::GD => &[Cephe::GD],
+        }
+    }
+}
+
 #[derive(Serialize, sqlx::FromRow)]
 pub struct Property {
     pub id: Uuid,
+    pub site_id: Uuid,
+    pub block_id: Uuid,
     pub daire_no: String,
     pub blok: String,
     pub kat: String,
@@ -33,7 +60,6 @@ pub struct Property {
     pub balkon_m2: Option<BigDecimal>,
     pub cephe: Option<Cephe>,
     pub kiraci_var_mi: bool,
-    pub sahip_id: Option<Uuid>,
 }
 
 #[derive(Serialize, sqlx::FromRow)]
@@ -50,14 +76,18 @@ pub struct PropertyWithCustomer {
     pub balkon_m2: Option<BigDecimal>,
     pub cephe: Option<Cephe>,
     pub kiraci_var_mi: bool,
-    pub sahip_id: Option<Uuid>,
+    pub sahip_idler: Vec<Uuid>,
     pub sahip_ad_soyad: Option<String>,
+    #[serde(skip)]
+    pub cover_photo_id: Option<Uuid>,
+    #[sqlx(skip)]
+    pub cover_thumbnail_url: Option<String>,
 }
 
 #[derive(Deserialize)]
 pub struct CreateProperty {
     pub daire_no: String,
-    pub blok: String,
+    pub block_id: Uuid,
     pub kat: String,
     pub kapi_no: i32,
     pub daire_tipi: String,
@@ -66,6 +96,34 @@ pub struct CreateProperty {
     pub net_m2: BigDecimal,
     pub balkon_m2: Option<BigDecimal>,
     pub cephe: Option<Cephe>,
-    pub kiraci_var_mi: bool,
-    pub sahip_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct PropertyFilter {
+    pub daire_no: Option<DaireNo>,
+    pub block_id: Option<Uuid>,
+    pub blok: Option<String>,
+    pub kat: Option<String>,
+    pub oda_sayisi: Option<String>,
+    pub daire_tipi: Option<String>,
+    pub cephe: Option<String>,
+    pub min_brut_m2: Option<BigDecimal>,
+    pub max_brut_m2: Option<BigDecimal>,
+    pub min_net_m2: Option<BigDecimal>,
+    pub max_net_m2: Option<BigDecimal>,
+    pub kiraci_var_mi: Option<bool>,
+    pub sahip_var_mi: Option<bool>,
+}
+
+#[derive(Clone, Serialize, sqlx::FromRow)]
+pub struct FacetCount {
+    pub value: Option<String>,
+    pub count: i64,
+}
+
+#[derive(Serialize)]
+pub struct PropertyFacets {
+    pub blok: Vec<FacetCount>,
+    pub oda_sayisi: Vec<FacetCount>,
+    pub cephe: Vec<FacetCount>,
 }
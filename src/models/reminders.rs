@@ -8,6 +8,7 @@ pub struct Reminder {
     pub reminder_date: chrono::DateTime<chrono::Utc>,
     pub created_by: Uuid,
     pub customer_id: Uuid,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[derive(Deserialize)]
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Deserialize)]
+pub struct ReportRange {
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+}
+
+#[derive(Serialize)]
+pub struct WeeklyCount {
+    pub week_start: chrono::NaiveDate,
+    pub count: i64,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct ConsultantPerformance {
+    pub danisan_id: Uuid,
+    pub ad_soyad: String,
+    pub assigned_customers: i64,
+    pub not_contacted_30: i64,
+    pub not_contacted_60: i64,
+    pub not_contacted_90: i64,
+    pub reminders_completed: i64,
+    pub reminders_overdue: i64,
+    pub notes_written: i64,
+    pub deals_closed: i64,
+    #[sqlx(skip)]
+    pub notes_per_week: Vec<WeeklyCount>,
+}
+
+#[derive(Serialize)]
+pub struct ConsultantReport {
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub consultants: Vec<ConsultantPerformance>,
+}
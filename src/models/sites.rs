@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Site {
+    pub id: Uuid,
+    pub name: String,
+    pub address: Option<String>,
+    pub ada: Option<String>,
+    pub parsel: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Block {
+    pub id: Uuid,
+    pub site_id: Uuid,
+    pub name: String,
+    pub floor_count: Option<i32>,
+    pub has_elevator: bool,
+    pub address: Option<String>,
+    pub ada: Option<String>,
+    pub parsel: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateSite {
+    pub name: String,
+    pub address: Option<String>,
+    pub ada: Option<String>,
+    pub parsel: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateBlock {
+    pub name: String,
+    pub floor_count: Option<i32>,
+    #[serde(default)]
+    pub has_elevator: bool,
+    pub address: Option<String>,
+    pub ada: Option<String>,
+    pub parsel: Option<String>,
+}
+
+#[derive(Default, Serialize)]
+pub struct Occupancy {
+    pub unit_count: i64,
+    pub owned_count: i64,
+    pub rented_count: i64,
+}
+
+impl Occupancy {
+    pub fn add(&mut self, unit: &UnitSummary) {
+        self.unit_count += 1;
+        if unit.sahip_var_mi {
+            self.owned_count += 1;
+        }
+        if unit.kiraci_var_mi {
+            self.rented_count += 1;
+        }
+    }
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct UnitSummary {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub block_id: Uuid,
+    pub daire_no: String,
+    pub kat: String,
+    pub kapi_no: i32,
+    pub oda_sayisi: String,
+    pub kiraci_var_mi: bool,
+    pub sahip_var_mi: bool,
+}
+
+#[derive(Serialize)]
+pub struct FloorNode {
+    pub kat: String,
+    pub occupancy: Occupancy,
+    pub units: Vec<UnitSummary>,
+}
+
+#[derive(Serialize)]
+pub struct BlockNode {
+    #[serde(flatten)]
+    pub block: Block,
+    pub occupancy: Occupancy,
+    pub floors: Vec<FloorNode>,
+}
+
+#[derive(Serialize)]
+pub struct SiteTree {
+    #[serde(flatten)]
+    pub site: Site,
+    pub occupancy: Occupancy,
+    pub blocks: Vec<BlockNode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "site_role_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SiteRole {
+    Manager,
+    Consultant,
+    Viewer,
+}
+
+#[derive(Clone, Copy)]
+pub struct SiteContext {
+    pub site_id: Uuid,
+    pub role: SiteRole,
+}
+
+impl SiteContext {
+    pub fn is_manager(&self) -> bool {
+        self.role == SiteRole::Manager
+    }
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct SiteWithRole {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub site: Site,
+    pub role: SiteRole,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct SiteMember {
+    pub user_id: Uuid,
+    pub name: String,
+    pub last_name: String,
+    pub email: String,
+    pub role: SiteRole,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Deserialize)]
+pub struct SetSiteMember {
+    pub role: SiteRole,
+}
@@ -0,0 +1,42 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+
+use crate::models::FacetCount;
+
+#[derive(Deserialize)]
+pub struct StatsQuery {
+    // Bu kadar gündür görüşülmeyen müşteriler sayılır
+    pub days: Option<i32>,
+}
+
+#[derive(Clone, Serialize, sqlx::FromRow)]
+pub struct UnitStats {
+    pub total_units: i64,
+    pub owned_units: i64,
+    pub unowned_units: i64,
+    pub tenant_occupied_units: i64,
+    pub tenant_occupied_ratio: f64,
+    pub total_brut_m2: BigDecimal,
+    pub avg_brut_m2: Option<BigDecimal>,
+    pub total_net_m2: BigDecimal,
+    pub avg_net_m2: Option<BigDecimal>,
+}
+
+#[derive(Clone, Serialize)]
+pub struct CustomerStats {
+    pub total_customers: i64,
+    pub by_uyruk: Vec<FacetCount>,
+    pub not_contacted_days: i32,
+    pub not_contacted: i64,
+}
+
+#[derive(Clone, Serialize)]
+pub struct PortfolioStats {
+    pub units: UnitStats,
+    pub by_blok: Vec<FacetCount>,
+    pub by_daire_tipi: Vec<FacetCount>,
+    pub by_oda_sayisi: Vec<FacetCount>,
+    pub customers: CustomerStats,
+    pub open_reminders: i64,
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+}
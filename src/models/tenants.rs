@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::LeaseWithDetails;
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Tenant {
+    pub id: Uuid,
+    pub ad_soyad: String,
+    pub gsm: Option<String>,
+    pub telefon: Option<String>,
+    pub email: Option<String>,
+    pub acil_kisi: Option<String>,
+    pub uyruk: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Serialize)]
+pub struct TenantDetail {
+    pub tenant_info: Tenant,
+    pub tenant_leases: Vec<LeaseWithDetails>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateTenant {
+    pub ad_soyad: String,
+    pub gsm: Option<String>,
+    pub telefon: Option<String>,
+    pub email: Option<String>,
+    pub acil_kisi: Option<String>,
+    pub uyruk: Option<String>,
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "ticket_category_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TicketCategory {
+    Plumbing,
+    Electrical,
+    Elevator,
+    Intercom,
+    CommonArea,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "ticket_priority_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TicketPriority {
+    Low,
+    Normal,
+    High,
+    Urgent,
+}
+
+impl TicketPriority {
+    pub fn sla(self) -> chrono::Duration {
+        match self {
+            TicketPriority::Low => chrono::Duration::days(7),
+            TicketPriority::Normal => chrono::Duration::days(3),
+            TicketPriority::High => chrono::Duration::hours(24),
+            TicketPriority::Urgent => chrono::Duration::hours(4),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "ticket_status_enum", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TicketStatus {
+    Open,
+    Assigned,
+    InProgress,
+    Resolved,
+}
+
+impl TicketStatus {
+    pub fn can_transition_to(self, next: TicketStatus) -> bool {
+        use TicketStatus::*;
+
+        matches!(
+            (self, next),
+            (Open, Assigned)
+                | (Assigned, InProgress)
+                | (Assigned, Open)
+                | (InProgress, Resolved)
+                | (InProgress, Assigned)
+                | (Resolved, Open)
+        )
+    }
+
+    // Çözülmüş iş yeniden açılmadan başkasına atanamaz
+    pub fn is_assignable(self) -> bool {
+        matches!(
+            self,
+            TicketStatus::Open | TicketStatus::Assigned | TicketStatus::InProgress
+        )
+    }
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Ticket {
+    pub id: Uuid,
+    pub property_id: Uuid,
+    pub reported_by: Option<Uuid>,
+    pub title: String,
+    pub description: Option<String>,
+    pub category: TicketCategory,
+    pub priority: TicketPriority,
+    pub status: TicketStatus,
+    pub assigned_to: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub due_at: chrono::DateTime<chrono::Utc>,
+    pub assigned_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct TicketWithDetails {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub ticket: Ticket,
+    pub daire_no: String,
+    pub reported_by_ad_soyad: Option<String>,
+    pub sla_breached: bool,
+}
+
+#[derive(Serialize)]
+pub struct TicketDetail {
+    pub ticket_info: TicketWithDetails,
+    pub ticket_comments: Vec<TicketComment>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct TicketComment {
+    pub id: Uuid,
+    pub ticket_id: Uuid,
+    pub comment: String,
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateTicket {
+    pub property_id: Uuid,
+    pub reported_by: Option<Uuid>,
+    pub title: String,
+    pub description: Option<String>,
+    pub category: Option<TicketCategory>,
+    pub priority: Option<TicketPriority>,
+}
+
+#[derive(Deserialize)]
+pub struct AssignTicket {
+    pub assigned_to: Uuid,
+}
+
+#[derive(Deserialize)]
+pub struct UpdateTicketStatus {
+    pub status: TicketStatus,
+}
+
+#[derive(Deserialize)]
+pub struct CreateTicketComment {
+    pub comment: String,
+}
+
+#[derive(Deserialize, Default)]
+pub struct TicketFilter {
+    pub property_id: Option<Uuid>,
+    pub reported_by: Option<Uuid>,
+    pub assigned_to: Option<Uuid>,
+    pub category: Option<TicketCategory>,
+    pub priority: Option<TicketPriority>,
+    pub status: Option<TicketStatus>,
+    pub sla_breached: Option<bool>,
+}
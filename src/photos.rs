@@ -0,0 +1,100 @@
+use std::io::Cursor;
+
+use image::{
+    DynamicImage, ImageDecoder, ImageFormat, ImageReader, codecs::jpeg::JpegEncoder,
+    imageops::FilterType,
+};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+const WEB_MAX_SIZE: u32 = 1600;
+const THUMB_MAX_SIZE: u32 = 400;
+const JPEG_QUALITY: u8 = 85;
+const URL_TTL_SECS: i64 = 3600;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PhotoError {
+    #[error("Desteklenmeyen görsel formatı.")]
+    UnsupportedFormat,
+    #[error("Görsel okunamadı: {0}")]
+    Decode(#[from] image::ImageError),
+}
+
+pub struct ProcessedPhoto {
+    pub width: u32,
+    pub height: u32,
+    pub web: Vec<u8>,
+    pub thumb: Vec<u8>,
+}
+
+// Çıktılar JPEG olarak yeniden kodlanır; EXIF (konum dahil) hiçbir varyanta taşınmaz.
+pub fn process(data: &[u8]) -> Result<ProcessedPhoto, PhotoError> {
+    let format = image::guess_format(data).map_err(|_| PhotoError::UnsupportedFormat)?;
+    if !matches!(
+        format,
+        ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP
+    ) {
+        return Err(PhotoError::UnsupportedFormat);
+    }
+
+    let mut decoder = ImageReader::with_format(Cursor::new(data), format).into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+
+    let web = if image.width() > WEB_MAX_SIZE || image.height() > WEB_MAX_SIZE {
+        image.resize(WEB_MAX_SIZE, WEB_MAX_SIZE, FilterType::Lanczos3)
+    } else {
+        image.clone()
+    };
+    let thumb = image.thumbnail(THUMB_MAX_SIZE, THUMB_MAX_SIZE);
+
+    Ok(ProcessedPhoto {
+        width: web.width(),
+        height: web.height(),
+        web: encode_jpeg(&web)?,
+        thumb: encode_jpeg(&thumb)?,
+    })
+}
+
+fn encode_jpeg(image: &DynamicImage) -> Result<Vec<u8>, PhotoError> {
+    let mut buf = Vec::new();
+    JpegEncoder::new_with_quality(&mut buf, JPEG_QUALITY).encode_image(&image.to_rgb8())?;
+    Ok(buf)
+}
+
+#[derive(Serialize, Deserialize)]
+struct PhotoUrlClaims {
+    sub: String,
+    exp: usize,
+}
+
+// <img src> başlık gönderemediği için varyantlar kısa ömürlü imzalı adreslerle sunulur.
+// Bitiş saati saat başına yuvarlanır; böylece adres bir süre sabit kalır ve tarayıcı önbelleği çalışır.
+pub fn signed_url(photo_id: Uuid, variant: &str) -> String {
+    let now = chrono::Utc::now().timestamp();
+    let claims = PhotoUrlClaims {
+        sub: format!("{}/{}", photo_id, variant),
+        exp: ((now / URL_TTL_SECS + 2) * URL_TTL_SECS) as usize,
+    };
+    let secret = std::env::var("JWT_SECRET").expect("JWT_SECRET ayarlanmamış");
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .expect("imza oluşturulamadı");
+
+    format!("/photos/{}/{}?token={}", photo_id, variant, token)
+}
+
+pub fn verify_url_token(photo_id: Uuid, variant: &str, token: &str) -> bool {
+    let secret = std::env::var("JWT_SECRET").expect("JWT_SECRET ayarlanmamış");
+    decode::<PhotoUrlClaims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::default(),
+    )
+    .is_ok_and(|data| data.claims.sub == format!("{}/{}", photo_id, variant))
+}
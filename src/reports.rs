@@ -0,0 +1,79 @@
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+use crate::models::ConsultantReport;
+
+const PERFORMANCE_HEADERS: [&str; 10] = [
+    "Danışman",
+    "Atanmış Müşteri",
+    "30 Gündür Görüşülmeyen",
+    "60 Gündür Görüşülmeyen",
+    "90 Gündür Görüşülmeyen",
+    "Tamamlanan Hatırlatma",
+    "Geciken Hatırlatma",
+    "Yazılan Not",
+    "Kapanan Satış",
+    "Haftalık Ortalama Not",
+];
+
+pub fn consultant_report_xlsx(report: &ConsultantReport) -> Result<Vec<u8>, XlsxError> {
+    let mut workbook = Workbook::new();
+    let bold = Format::new().set_bold();
+    let date_format = Format::new().set_num_format("dd.mm.yyyy");
+    let decimal = Format::new().set_num_format("0.00");
+
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("Danışman Performansı")?;
+    sheet.write_string_with_format(0, 0, "Başlangıç", &bold)?;
+    sheet.write_date_with_format(0, 1, report.from, &date_format)?;
+    sheet.write_string_with_format(1, 0, "Bitiş", &bold)?;
+    sheet.write_date_with_format(1, 1, report.to, &date_format)?;
+
+    for (col, title) in PERFORMANCE_HEADERS.iter().enumerate() {
+        sheet.write_string_with_format(3, col as u16, *title, &bold)?;
+    }
+
+    for (i, c) in report.consultants.iter().enumerate() {
+        let row = 4 + i as u32;
+        let weeks = c.notes_per_week.len().max(1) as f64;
+        sheet.write_string(row, 0, &c.ad_soyad)?;
+        for (col, value) in [
+            c.assigned_customers,
+            c.not_contacted_30,
+            c.not_contacted_60,
+            c.not_contacted_90,
+            c.reminders_completed,
+            c.reminders_overdue,
+            c.notes_written,
+            c.deals_closed,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            sheet.write_number(row, 1 + col as u16, value as f64)?;
+        }
+        sheet.write_number_with_format(row, 9, c.notes_written as f64 / weeks, &decimal)?;
+    }
+    sheet.set_column_width(0, 28)?;
+    sheet.set_column_range_width(1, 9, 16)?;
+    sheet.set_freeze_panes(4, 1)?;
+
+    let weekly = workbook.add_worksheet();
+    weekly.set_name("Haftalık Notlar")?;
+    weekly.write_string_with_format(0, 0, "Danışman", &bold)?;
+    if let Some(first) = report.consultants.first() {
+        for (col, week) in first.notes_per_week.iter().enumerate() {
+            weekly.write_date_with_format(0, 1 + col as u16, week.week_start, &date_format)?;
+        }
+    }
+    for (i, c) in report.consultants.iter().enumerate() {
+        let row = 1 + i as u32;
+        weekly.write_string(row, 0, &c.ad_soyad)?;
+        for (col, week) in c.notes_per_week.iter().enumerate() {
+            weekly.write_number(row, 1 + col as u16, week.count as f64)?;
+        }
+    }
+    weekly.set_column_width(0, 28)?;
+    weekly.set_freeze_panes(1, 1)?;
+
+    workbook.save_to_buffer()
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+mod local;
+pub use local::LocalStorage;
+
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("Dosya bulunamadı.")]
+    NotFound,
+    #[error("Geçersiz dosya anahtarı.")]
+    InvalidKey,
+    #[error("Depolama hatası: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[async_trait]
+pub trait Storage: Send + Sync {
+    async fn put(&self, key: &str, data: &[u8]) -> Result<(), StorageError>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>, StorageError>;
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+
+// Sorgulardaki 'Europe/Istanbul' ile aynı saat dilimi; sunucunun yerel saatine bakılmaz
+pub const SITE_TZ: Tz = chrono_tz::Europe::Istanbul;
+
+pub fn today() -> NaiveDate {
+    Utc::now().with_timezone(&SITE_TZ).date_naive()
+}
+
+pub fn local(value: DateTime<Utc>) -> NaiveDateTime {
+    value.with_timezone(&SITE_TZ).naive_local()
+}